@@ -90,8 +90,12 @@ impl<T: HasDataLayout> PointerArithmetic for T {}
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, TyEncodable, TyDecodable, Hash)]
 #[derive(HashStable)]
 pub struct Pointer<Tag = ()> {
+    /// The allocation this pointer is derived from.
     pub alloc_id: AllocId,
+    /// Offset into that allocation, in bytes. Nothing forces this to be in-bounds; the bounds
+    /// are checked by `Memory` when the pointer is used for an access or for in-bounds arithmetic.
     pub offset: Size,
+    /// Machine-specific provenance information, `()` if the machine does not track any.
     pub tag: Tag,
 }
 
@@ -20,6 +20,7 @@ pub enum ConstEvalErrKind {
     ModifiedGlobal,
     AssertFailure(AssertKind<ConstInt>),
     Panic { msg: Symbol, line: u32, col: u32, file: Symbol },
+    Abort,
 }
 
 // The errors become `MachineStop` with plain strings when being raised.
@@ -46,6 +47,7 @@ impl fmt::Display for ConstEvalErrKind {
             Panic { msg, line, col, file } => {
                 write!(f, "the evaluated program panicked at '{}', {}:{}:{}", msg, file, line, col)
             }
+            Abort => write!(f, "the evaluated program aborted execution"),
         }
     }
 }
@@ -74,6 +76,7 @@ impl<'tcx> ConstEvalErr<'tcx> {
         'tcx: 'mir,
     {
         error.print_backtrace();
+        trace!("memory at the time of the error:\n{:?}", ecx.memory.dump_all_allocs());
         let stacktrace = ecx.generate_stacktrace();
         ConstEvalErr { error: error.kind, stacktrace, span: span.unwrap_or_else(|| ecx.cur_span()) }
     }
@@ -165,8 +168,31 @@ impl<'tcx> ConstEvalErr<'tcx> {
             }
             // Add spans for the stacktrace. Don't print a single-line backtrace though.
             if self.stacktrace.len() > 1 {
-                for frame_info in &self.stacktrace {
-                    err.span_label(frame_info.span, frame_info.to_string());
+                if let err_exhaust!(StackFrameLimitReached) = self.error {
+                    // Runaway recursion repeats the same frame until the limit is hit. Collapse
+                    // runs of identical consecutive frames, but keep frames that only recur
+                    // further up the stack so that the labels still describe the actual stack.
+                    let mut frames: Vec<(Span, String, usize)> = Vec::new();
+                    for frame_info in &self.stacktrace {
+                        let label = frame_info.to_string();
+                        match frames.last_mut() {
+                            Some((span, l, count)) if *span == frame_info.span && *l == label => {
+                                *count += 1
+                            }
+                            _ => frames.push((frame_info.span, label, 1)),
+                        }
+                    }
+                    for (span, label, count) in frames {
+                        if count > 1 {
+                            err.span_label(span, format!("{} ({} frames)", label, count));
+                        } else {
+                            err.span_label(span, label);
+                        }
+                    }
+                } else {
+                    for frame_info in &self.stacktrace {
+                        err.span_label(frame_info.span, frame_info.to_string());
+                    }
                 }
             }
             // Let the caller finish the job.
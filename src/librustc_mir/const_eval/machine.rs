@@ -84,6 +84,18 @@ impl<'mir, 'tcx> InterpCx<'mir, 'tcx, CompileTimeInterpreter<'mir, 'tcx>> {
             Ok(())
         }
     }
+
+    /// Closures are never `const fn`, but the ones defined in a const context can only be called
+    /// there once the const checks are skipped. Their bodies are evaluated like the rest of the
+    /// const context, so they are allowed to run.
+    fn is_const_context_closure(&self, def_id: DefId) -> bool {
+        self.tcx.is_closure(def_id)
+            && self
+                .tcx
+                .closure_base_def_id(def_id)
+                .as_local()
+                .map_or(false, |base| self.tcx.hir().body_const_context(base).is_some())
+    }
 }
 
 /// Extra machine state for CTFE, and the Machine instance
@@ -201,7 +213,7 @@ impl<'mir, 'tcx> interpret::Machine<'mir, 'tcx> for CompileTimeInterpreter<'mir,
                 if ecx.try_eval_const_fn_call(instance, ret, args)? {
                     return Ok(None);
                 }
-            } else {
+            } else if !ecx.is_const_context_closure(def.did) {
                 // Some functions we support even if they are non-const -- but avoid testing
                 // that for const fn!
                 ecx.hook_panic_fn(instance, args)?;
@@ -267,6 +279,10 @@ impl<'mir, 'tcx> interpret::Machine<'mir, 'tcx> for CompileTimeInterpreter<'mir,
         Err(ConstEvalErrKind::AssertFailure(err).into())
     }
 
+    fn abort(_ecx: &mut InterpCx<'mir, 'tcx, Self>) -> InterpResult<'tcx, !> {
+        Err(ConstEvalErrKind::Abort.into())
+    }
+
     fn ptr_to_int(_mem: &Memory<'mir, 'tcx, Self>, _ptr: Pointer) -> InterpResult<'tcx, u64> {
         Err(ConstEvalErrKind::NeedsRfc("pointer-to-integer cast".to_string()).into())
     }
@@ -4,6 +4,7 @@ use rustc_apfloat::ieee::{Double, Single};
 use rustc_apfloat::{Float, FloatConvert};
 use rustc_ast::FloatTy;
 use rustc_attr as attr;
+use rustc_data_structures::stack::ensure_sufficient_stack;
 use rustc_middle::mir::interpret::{InterpResult, PointerArithmetic, Scalar};
 use rustc_middle::mir::CastKind;
 use rustc_middle::ty::adjustment::PointerCast;
@@ -340,7 +341,11 @@ impl<'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
                     if src_field.layout.ty == cast_ty_field.ty {
                         self.copy_op(src_field, dst_field)?;
                     } else {
-                        self.unsize_into(src_field, cast_ty_field, dst_field)?;
+                        // Nested unsizing follows the type structure; grow the host stack
+                        // instead of overflowing it.
+                        ensure_sufficient_stack(|| {
+                            self.unsize_into(src_field, cast_ty_field, dst_field)
+                        })?;
                     }
                 }
                 Ok(())
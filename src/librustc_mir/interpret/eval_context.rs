@@ -3,6 +3,7 @@ use std::fmt;
 use std::mem;
 
 use rustc_data_structures::fx::FxHashMap;
+use rustc_data_structures::stack::ensure_sufficient_stack;
 use rustc_data_structures::stable_hasher::{HashStable, StableHasher};
 use rustc_hir::{self as hir, def::DefKind, def_id::DefId, definitions::DefPathData};
 use rustc_index::vec::IndexVec;
@@ -10,7 +11,7 @@ use rustc_macros::HashStable;
 use rustc_middle::ich::StableHashingContext;
 use rustc_middle::mir;
 use rustc_middle::mir::interpret::{
-    sign_extend, truncate, GlobalId, InterpResult, Pointer, Scalar,
+    sign_extend, truncate, AllocId, GlobalId, InterpResult, Pointer, Scalar,
 };
 use rustc_middle::ty::layout::{self, TyAndLayout};
 use rustc_middle::ty::{
@@ -251,6 +252,8 @@ impl<'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> LayoutOf for InterpCx<'mir, 'tcx,
     type Ty = Ty<'tcx>;
     type TyAndLayout = InterpResult<'tcx, TyAndLayout<'tcx>>;
 
+    /// `layout_of` is a query, so repeated lookups of the same type are served from the query
+    /// cache; locals additionally cache their layout in `LocalState`.
     #[inline]
     fn layout_of(&self, ty: Ty<'tcx>) -> Self::TyAndLayout {
         self.tcx
@@ -331,6 +334,20 @@ impl<'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
         }
     }
 
+    /// Prepares this context for another evaluation: clears the stack and removes all
+    /// allocations not reachable from `static_roots` or a `Global` allocation, along with the
+    /// vtables that may have pointed into them.
+    ///
+    /// Machine state other than the stack is left untouched. The caller has to reset whatever
+    /// per-evaluation state the machine keeps itself (e.g. a step counter), and any pointer the
+    /// machine holds on to must either be reachable from `static_roots` or never be used again:
+    /// the allocations it points to are gone and it is reported as dangling.
+    pub fn reset(&mut self, static_roots: &[AllocId]) {
+        self.stack_mut().clear();
+        self.memory.clear_local_allocations(static_roots);
+        self.vtables.clear();
+    }
+
     #[inline(always)]
     pub fn cur_span(&self) -> Span {
         self.stack().last().map(|f| f.current_span()).unwrap_or(self.tcx.span)
@@ -556,8 +573,11 @@ impl<'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
                 // Recurse to get the size of the dynamically sized field (must be
                 // the last field).  Can't have foreign types here, how would we
                 // adjust alignment and size for them?
+                // The recursion follows the type structure, so grow the host stack if needed.
                 let field = layout.field(self, layout.fields.count() - 1)?;
-                let (unsized_size, unsized_align) = match self.size_and_align_of(metadata, field)? {
+                let size_and_align =
+                    ensure_sufficient_stack(|| self.size_and_align_of(metadata, field))?;
+                let (unsized_size, unsized_align) = match size_and_align {
                     Some(size_and_align) => size_and_align,
                     None => {
                         // A field with extern type.  If this field is at offset 0, we behave
@@ -949,6 +969,41 @@ impl<'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
     }
 }
 
+/// A copy of the entire interpreter state, taken by `InterpCx::snapshot`.
+pub struct InterpSnapshot<'mir, 'tcx, M: Machine<'mir, 'tcx>> {
+    machine: M,
+    memory: Memory<'mir, 'tcx, M>,
+    vtables:
+        FxHashMap<(Ty<'tcx>, Option<ty::PolyExistentialTraitRef<'tcx>>), Pointer<M::PointerTag>>,
+}
+
+impl<'mir, 'tcx: 'mir, M> InterpCx<'mir, 'tcx, M>
+where
+    M: Machine<'mir, 'tcx> + Clone,
+    M::MemoryMap: Clone,
+    M::MemoryExtra: Clone,
+{
+    /// Takes a snapshot of the machine (including the stack) and of all of memory, so that
+    /// execution can later be rolled back with `restore`.
+    ///
+    /// This is a deep copy of every local allocation, so it is expensive; nothing is
+    /// tracked between snapshots.
+    pub fn snapshot(&self) -> InterpSnapshot<'mir, 'tcx, M> {
+        InterpSnapshot {
+            machine: self.machine.clone(),
+            memory: self.memory.clone(),
+            vtables: self.vtables.clone(),
+        }
+    }
+
+    /// Rolls the interpreter back to the state it was in when `snapshot` was taken.
+    pub fn restore(&mut self, snapshot: InterpSnapshot<'mir, 'tcx, M>) {
+        self.machine = snapshot.machine;
+        self.memory = snapshot.memory;
+        self.vtables = snapshot.vtables;
+    }
+}
+
 #[doc(hidden)]
 /// Helper struct for the `dump_place` function.
 pub struct PlacePrinter<'a, 'mir, 'tcx, M: Machine<'mir, 'tcx>> {
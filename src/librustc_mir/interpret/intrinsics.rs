@@ -3,7 +3,10 @@
 //! and miri.
 
 use std::convert::TryFrom;
+use std::iter;
 
+use rustc_apfloat::{Float, Round};
+use rustc_ast::FloatTy;
 use rustc_hir::def_id::DefId;
 use rustc_middle::mir::{
     self,
@@ -14,10 +17,11 @@ use rustc_middle::ty;
 use rustc_middle::ty::subst::SubstsRef;
 use rustc_middle::ty::{Ty, TyCtxt};
 use rustc_span::symbol::{sym, Symbol};
-use rustc_target::abi::{Abi, LayoutOf as _, Primitive, Size};
+use rustc_target::abi::{Abi, Align, LayoutOf as _, Primitive, Size};
 
 use super::{
-    util::ensure_monomorphic_enough, CheckInAllocMsg, ImmTy, InterpCx, Machine, OpTy, PlaceTy,
+    util::ensure_monomorphic_enough, CheckInAllocMsg, ImmTy, Immediate, InterpCx, MPlaceTy,
+    Machine, OpTy, PlaceTy,
 };
 
 mod caller_location;
@@ -73,6 +77,8 @@ crate fn eval_nullary_intrinsic<'tcx>(
         }
         sym::type_id => {
             ensure_monomorphic_enough(tcx, tp_ty)?;
+            // The width of the id is dictated by the signature of the `type_id` intrinsic. If
+            // that ever gets widened, the type used in `emulate_intrinsic` must follow suit.
             ConstValue::from_u64(tcx.type_id_hash(tp_ty))
         }
         sym::variant_count => {
@@ -290,6 +296,11 @@ impl<'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
                 }
                 self.write_scalar(val, dest)?;
             }
+            sym::exact_div => {
+                let l = self.read_immediate(args[0])?;
+                let r = self.read_immediate(args[1])?;
+                self.exact_div(l, r, dest)?;
+            }
             sym::rotate_left | sym::rotate_right => {
                 // rotate_left: (X << (S % BW)) | (X >> ((BW - S) % BW))
                 // rotate_right: (X << ((BW - S) % BW)) | (X >> (S % BW))
@@ -334,7 +345,7 @@ impl<'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
                 let cmp = if intrinsic_name == sym::ptr_guaranteed_eq {
                     self.guaranteed_eq(a, b)
                 } else {
-                    self.guaranteed_ne(a, b)
+                    self.guaranteed_ne(a, b)?
                 };
                 self.write_scalar(Scalar::from_bool(cmp), dest)?;
             }
@@ -437,9 +448,141 @@ impl<'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
                 self.copy_op(self.operand_index(args[0], index)?, dest)?;
             }
             sym::likely | sym::unlikely => {
-                // These just return their argument
+                // These just return their argument. `copy_op` writes immediates straight into
+                // locals, so this does not force anything into memory.
                 self.copy_op(args[0], dest)?;
             }
+            sym::assume => {
+                let cond = self.read_scalar(args[0])?.to_bool()?;
+                if !cond {
+                    throw_ub_format!("`assume` intrinsic called with `false`");
+                }
+            }
+            sym::floorf32
+            | sym::floorf64
+            | sym::ceilf32
+            | sym::ceilf64
+            | sym::truncf32
+            | sym::truncf64
+            | sym::roundf32
+            | sym::roundf64
+            | sym::rintf32
+            | sym::rintf64
+            | sym::nearbyintf32
+            | sym::nearbyintf64 => {
+                let round = match intrinsic_name {
+                    sym::floorf32 | sym::floorf64 => Round::TowardNegative,
+                    sym::ceilf32 | sym::ceilf64 => Round::TowardPositive,
+                    sym::truncf32 | sym::truncf64 => Round::TowardZero,
+                    sym::roundf32 | sym::roundf64 => Round::NearestTiesToAway,
+                    // We do not model rounding modes, so these always use the default one.
+                    _ => Round::NearestTiesToEven,
+                };
+                let val = self.read_scalar(args[0])?;
+                let res = match dest.layout.ty.kind {
+                    ty::Float(FloatTy::F32) => {
+                        Scalar::from_f32(val.to_f32()?.round_to_integral(round).value)
+                    }
+                    ty::Float(FloatTy::F64) => {
+                        Scalar::from_f64(val.to_f64()?.round_to_integral(round).value)
+                    }
+                    _ => bug!("`{}` called with non-float type", intrinsic_name),
+                };
+                self.write_scalar(res, dest)?;
+            }
+            sym::fmaf32 => {
+                let a = self.read_scalar(args[0])?.to_f32()?;
+                let b = self.read_scalar(args[1])?.to_f32()?;
+                let c = self.read_scalar(args[2])?.to_f32()?;
+                self.write_scalar(Scalar::from_f32(a.mul_add(b, c).value), dest)?;
+            }
+            sym::fmaf64 => {
+                let a = self.read_scalar(args[0])?.to_f64()?;
+                let b = self.read_scalar(args[1])?.to_f64()?;
+                let c = self.read_scalar(args[2])?.to_f64()?;
+                self.write_scalar(Scalar::from_f64(a.mul_add(b, c).value), dest)?;
+            }
+            // If exactly one operand is NaN, these return the other one.
+            sym::minnumf32 | sym::maxnumf32 => {
+                let a = self.read_scalar(args[0])?.to_f32()?;
+                let b = self.read_scalar(args[1])?.to_f32()?;
+                let res = if intrinsic_name == sym::minnumf32 { a.min(b) } else { a.max(b) };
+                self.write_scalar(Scalar::from_f32(res), dest)?;
+            }
+            sym::minnumf64 | sym::maxnumf64 => {
+                let a = self.read_scalar(args[0])?.to_f64()?;
+                let b = self.read_scalar(args[1])?.to_f64()?;
+                let res = if intrinsic_name == sym::minnumf64 { a.min(b) } else { a.max(b) };
+                self.write_scalar(Scalar::from_f64(res), dest)?;
+            }
+            sym::copysignf32 => {
+                let a = self.read_scalar(args[0])?.to_f32()?;
+                let b = self.read_scalar(args[1])?.to_f32()?;
+                self.write_scalar(Scalar::from_f32(a.copy_sign(b)), dest)?;
+            }
+            sym::copysignf64 => {
+                let a = self.read_scalar(args[0])?.to_f64()?;
+                let b = self.read_scalar(args[1])?.to_f64()?;
+                self.write_scalar(Scalar::from_f64(a.copy_sign(b)), dest)?;
+            }
+            sym::breakpoint => M::breakpoint(self)?,
+            sym::volatile_load => {
+                let place = self.deref_operand(args[0])?;
+                self.check_volatile_access(place)?;
+                self.copy_op(place.into(), dest)?;
+            }
+            sym::volatile_store => {
+                let place = self.deref_operand(args[0])?;
+                self.check_volatile_access(place)?;
+                self.copy_op(args[1], place.into())?;
+            }
+            sym::copy | sym::copy_nonoverlapping => {
+                let nonoverlapping = intrinsic_name == sym::copy_nonoverlapping;
+                self.copy_intrinsic(args[0], args[1], args[2], nonoverlapping)?;
+            }
+            // The interpreter does not model volatility, so these behave like their plain
+            // counterparts. Note that the destination comes first here.
+            sym::volatile_copy_memory | sym::volatile_copy_nonoverlapping_memory => {
+                let nonoverlapping = intrinsic_name == sym::volatile_copy_nonoverlapping_memory;
+                self.copy_intrinsic(args[1], args[0], args[2], nonoverlapping)?;
+            }
+            sym::write_bytes | sym::volatile_set_memory => {
+                self.write_bytes_intrinsic(args[0], args[1], args[2])?;
+            }
+            // The interpreter is single-threaded, so atomic accesses are just ordinary accesses,
+            // independent of the ordering encoded in the intrinsic name.
+            name if name.as_str().starts_with("atomic_load") => {
+                let place = self.deref_atomic_operand(args[0])?;
+                self.copy_op(place.into(), dest)?;
+            }
+            name if name.as_str().starts_with("atomic_store") => {
+                let place = self.deref_atomic_operand(args[0])?;
+                self.copy_op(args[1], place.into())?;
+            }
+            name if name.as_str().starts_with("atomic_cxchg") => {
+                self.atomic_compare_exchange(args, dest)?;
+            }
+            name if name.as_str().starts_with("atomic_xchg") => {
+                self.atomic_op(args, dest, None)?;
+            }
+            name if name.as_str().starts_with("atomic_xadd") => {
+                self.atomic_op(args, dest, Some((BinOp::Add, false)))?;
+            }
+            name if name.as_str().starts_with("atomic_xsub") => {
+                self.atomic_op(args, dest, Some((BinOp::Sub, false)))?;
+            }
+            name if name.as_str().starts_with("atomic_and") => {
+                self.atomic_op(args, dest, Some((BinOp::BitAnd, false)))?;
+            }
+            name if name.as_str().starts_with("atomic_nand") => {
+                self.atomic_op(args, dest, Some((BinOp::BitAnd, true)))?;
+            }
+            name if name.as_str().starts_with("atomic_or") => {
+                self.atomic_op(args, dest, Some((BinOp::BitOr, false)))?;
+            }
+            name if name.as_str().starts_with("atomic_xor") => {
+                self.atomic_op(args, dest, Some((BinOp::BitXor, false)))?;
+            }
             _ => return Ok(false),
         }
 
@@ -461,14 +604,18 @@ impl<'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
         }
     }
 
-    fn guaranteed_ne(&mut self, a: Scalar<M::PointerTag>, b: Scalar<M::PointerTag>) -> bool {
-        match (a, b) {
+    fn guaranteed_ne(
+        &mut self,
+        a: Scalar<M::PointerTag>,
+        b: Scalar<M::PointerTag>,
+    ) -> InterpResult<'tcx, bool> {
+        Ok(match (a, b) {
             // Comparisons between integers are always known.
             (Scalar::Raw { .. }, Scalar::Raw { .. }) => a != b,
             // Comparisons of abstract pointers with null pointers are known if the pointer
             // is in bounds, because if they are in bounds, the pointer can't be null.
             (Scalar::Raw { data: 0, .. }, Scalar::Ptr(ptr))
-            | (Scalar::Ptr(ptr), Scalar::Raw { data: 0, .. }) => !self.memory.ptr_may_be_null(ptr),
+            | (Scalar::Ptr(ptr), Scalar::Raw { data: 0, .. }) => !self.memory.ptr_may_be_null(ptr)?,
             // Inequality with integers other than null can never be known for sure.
             (Scalar::Raw { .. }, Scalar::Ptr(_)) | (Scalar::Ptr(_), Scalar::Raw { .. }) => false,
             // FIXME: return `true` for at least some comparisons where we can reliably
@@ -476,7 +623,134 @@ impl<'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
             // Examples include comparison of addresses in static items, for these we can
             // give reliable results.
             (Scalar::Ptr(_), Scalar::Ptr(_)) => false,
+        })
+    }
+
+    /// Checks that a volatile access to `place` is in bounds, and aligned if
+    /// `M::enforce_volatile_alignment` says so.
+    fn check_volatile_access(&self, place: MPlaceTy<'tcx, M::PointerTag>) -> InterpResult<'tcx> {
+        let align =
+            M::enforce_volatile_alignment(&self.memory.extra).then_some(place.layout.align.abi);
+        self.memory.check_ptr_access_align(
+            place.ptr,
+            place.layout.size,
+            align,
+            CheckInAllocMsg::MemoryAccessTest,
+        )?;
+        Ok(())
+    }
+
+    /// Performs an atomic read-modify-write operation and returns the old value. `op` is the
+    /// operation to combine the old value with the argument (negating the result if the flag
+    /// is set), or `None` to just replace the old value.
+    fn atomic_op(
+        &mut self,
+        args: &[OpTy<'tcx, M::PointerTag>],
+        dest: PlaceTy<'tcx, M::PointerTag>,
+        op: Option<(BinOp, bool)>,
+    ) -> InterpResult<'tcx> {
+        let place = self.deref_atomic_operand(args[0])?;
+        let old = self.read_immediate(place.into())?;
+        let rhs = self.read_immediate(args[1])?;
+        let new = match op {
+            None => rhs,
+            Some((op, neg)) => {
+                let val = self.binary_op(op, old, rhs)?;
+                if neg {
+                    self.unary_op(mir::UnOp::Not, val)?
+                } else {
+                    val
+                }
+            }
+        };
+        self.write_immediate(*old, dest)?;
+        self.write_immediate(*new, place.into())?;
+        Ok(())
+    }
+
+    /// Performs an atomic compare-exchange, returning the old value and whether the exchange
+    /// happened. Weak compare-exchanges never fail spuriously.
+    fn atomic_compare_exchange(
+        &mut self,
+        args: &[OpTy<'tcx, M::PointerTag>],
+        dest: PlaceTy<'tcx, M::PointerTag>,
+    ) -> InterpResult<'tcx> {
+        let place = self.deref_atomic_operand(args[0])?;
+        let expect_old = self.read_immediate(args[1])?;
+        let new = self.read_scalar(args[2])?;
+        let old = self.read_immediate(place.into())?;
+        let eq = self.binary_op(BinOp::Eq, old, expect_old)?.to_scalar()?.to_bool()?;
+        let res = Immediate::ScalarPair(old.to_scalar_or_uninit(), Scalar::from_bool(eq).into());
+        self.write_immediate(res, dest)?;
+        if eq {
+            self.write_scalar(new, place.into())?;
         }
+        Ok(())
+    }
+
+    /// Dereferences the pointer an atomic intrinsic operates on. If the machine enforces
+    /// alignment, the pointer must be aligned to the size of the access, which can be more than
+    /// the alignment of the type (e.g. `u64` on 32-bit targets).
+    fn deref_atomic_operand(
+        &self,
+        ptr: OpTy<'tcx, M::PointerTag>,
+    ) -> InterpResult<'tcx, MPlaceTy<'tcx, M::PointerTag>> {
+        let place = self.deref_operand(ptr)?;
+        let align = Align::from_bytes(place.layout.size.bytes()).unwrap();
+        self.memory.check_ptr_access(place.ptr, place.layout.size, align)?;
+        Ok(place)
+    }
+
+    /// Copies `count` values of the pointee type of `src` to `dst`, as the `copy` family of
+    /// intrinsics does. If the machine enforces alignment, both pointers must be aligned for
+    /// that type, even if nothing is copied.
+    fn copy_intrinsic(
+        &mut self,
+        src: OpTy<'tcx, M::PointerTag>,
+        dst: OpTy<'tcx, M::PointerTag>,
+        count: OpTy<'tcx, M::PointerTag>,
+        nonoverlapping: bool,
+    ) -> InterpResult<'tcx> {
+        let count = self.read_scalar(count)?.to_machine_usize(self)?;
+        let pointee_ty = src.layout.ty.builtin_deref(true).unwrap().ty;
+        let layout = self.layout_of(pointee_ty)?;
+        let align = layout.align.abi;
+        let size = layout
+            .size
+            .checked_mul(count, self)
+            .ok_or_else(|| err_ub_format!("overflow computing total size of copy"))?;
+
+        let src = self.read_scalar(src)?.check_init()?;
+        let dst = self.read_scalar(dst)?.check_init()?;
+        let src = self.memory.check_ptr_access(src, size, align)?;
+        let dst = self.memory.check_ptr_access(dst, size, align)?;
+        if let (Some(src), Some(dst)) = (src, dst) {
+            self.memory.copy(src, dst, size, nonoverlapping)?;
+        }
+        Ok(())
+    }
+
+    /// Sets `count` values of the pointee type of `dst` to all-`byte`, as `write_bytes` does.
+    /// If the machine enforces alignment, `dst` must be aligned for that type, even if nothing
+    /// is written.
+    fn write_bytes_intrinsic(
+        &mut self,
+        dst: OpTy<'tcx, M::PointerTag>,
+        byte: OpTy<'tcx, M::PointerTag>,
+        count: OpTy<'tcx, M::PointerTag>,
+    ) -> InterpResult<'tcx> {
+        let count = self.read_scalar(count)?.to_machine_usize(self)?;
+        let pointee_ty = dst.layout.ty.builtin_deref(true).unwrap().ty;
+        let layout = self.layout_of(pointee_ty)?;
+        let size = layout
+            .size
+            .checked_mul(count, self)
+            .ok_or_else(|| err_ub_format!("overflow computing total size of write_bytes"))?;
+
+        let byte = self.read_scalar(byte)?.to_u8()?;
+        let dst = self.read_scalar(dst)?.check_init()?;
+        self.memory.check_ptr_access(dst, size, layout.align.abi)?;
+        self.memory.write_bytes(dst, iter::repeat(byte).take(size.bytes_usize()))
     }
 
     pub fn exact_div(
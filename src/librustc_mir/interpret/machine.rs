@@ -5,6 +5,7 @@
 use std::borrow::{Borrow, Cow};
 use std::hash::Hash;
 
+use rustc_ast::{InlineAsmOptions, InlineAsmTemplatePiece};
 use rustc_middle::mir;
 use rustc_middle::ty::{self, Ty};
 use rustc_span::def_id::DefId;
@@ -14,6 +15,17 @@ use super::{
     LocalValue, MemPlace, Memory, MemoryKind, OpTy, Operand, PlaceTy, Pointer, Scalar,
 };
 
+/// Data returned by Machine::overflow_mode,
+/// to decide how unchecked arithmetic handles overflow
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum OverflowMode {
+    /// Wrap around, like a release build does.
+    Wrap,
+
+    /// Raise an `ArithOverflow` error.
+    Error,
+}
+
 /// Data returned by Machine::stack_pop,
 /// to provide further control over the popping of the stack frame
 #[derive(Eq, PartialEq, Debug, Copy, Clone)]
@@ -126,9 +138,23 @@ pub trait Machine<'mir, 'tcx>: Sized {
     /// custom alignment logic based on whatever the integer address happens to be.
     fn force_int_for_alignment_check(memory_extra: &Self::MemoryExtra) -> bool;
 
+    /// Whether `volatile_load` and `volatile_store` check alignment. Volatile accesses are
+    /// mostly used for device memory, so a machine may want to decide this separately.
+    #[inline(always)]
+    fn enforce_volatile_alignment(memory_extra: &Self::MemoryExtra) -> bool {
+        Self::enforce_alignment(memory_extra)
+    }
+
     /// Whether to enforce the validity invariant
     fn enforce_validity(ecx: &InterpCx<'mir, 'tcx, Self>) -> bool;
 
+    /// What to do when a `BinaryOp` overflows. MIR only emits unchecked arithmetic when
+    /// overflow checks are disabled, so by default we wrap just like a release build would.
+    #[inline(always)]
+    fn overflow_mode(_ecx: &InterpCx<'mir, 'tcx, Self>) -> OverflowMode {
+        OverflowMode::Wrap
+    }
+
     /// Entry point to all function calls.
     ///
     /// Returns either the mir to use for the call, or `None` if execution should
@@ -139,6 +165,10 @@ pub trait Machine<'mir, 'tcx>: Sized {
     /// nor just jump to `ret`, but instead push their own stack frame.)
     /// Passing `dest`and `ret` in the same `Option` proved very annoying when only one of them
     /// was used.
+    ///
+    /// The returned MIR only has to outlive `'mir`; it does not need to come from `tcx`. Embedders
+    /// that build MIR themselves (for example incrementally, in a REPL) can hand it out here
+    /// instead of going through `InterpCx::load_mir`.
     fn find_mir_or_eval_fn(
         ecx: &mut InterpCx<'mir, 'tcx, Self>,
         instance: ty::Instance<'tcx>,
@@ -179,6 +209,24 @@ pub trait Machine<'mir, 'tcx>: Sized {
         throw_unsup_format!("aborting execution is not supported")
     }
 
+    /// Called to evaluate `InlineAsm` MIR terminators. Machines can use this to emulate the
+    /// inline assembly their programs need; they are then responsible for continuing at
+    /// `destination` (which is `None` if the assembly never returns).
+    fn eval_inline_asm(
+        _ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        _template: &'tcx [InlineAsmTemplatePiece],
+        _operands: &[mir::InlineAsmOperand<'tcx>],
+        _options: InlineAsmOptions,
+        _destination: Option<mir::BasicBlock>,
+    ) -> InterpResult<'tcx> {
+        throw_unsup_format!("inline assembly is not supported")
+    }
+
+    /// Called to evaluate the `breakpoint` intrinsic.
+    fn breakpoint(_ecx: &mut InterpCx<'mir, 'tcx, Self>) -> InterpResult<'tcx> {
+        throw_unsup_format!("breakpoints are not supported")
+    }
+
     /// Called for all binary operations where the LHS has pointer type.
     ///
     /// Returns a (value, overflowed) pair if the operation succeeded
@@ -222,6 +270,14 @@ pub trait Machine<'mir, 'tcx>: Sized {
         ecx.stack_mut()[frame].locals[local].access_mut()
     }
 
+    /// Called before a statement is executed. The statement's location is available through
+    /// `ecx.frame().loc`, and the full stack and memory can be inspected from here.
+    /// Embedders can use this to single-step execution or to stop at particular spans.
+    #[inline]
+    fn before_statement(_ecx: &mut InterpCx<'mir, 'tcx, Self>) -> InterpResult<'tcx> {
+        Ok(())
+    }
+
     /// Called before a basic block terminator is executed.
     /// You can use this to detect endlessly running programs.
     #[inline]
@@ -339,6 +395,12 @@ pub trait Machine<'mir, 'tcx>: Sized {
         Ok(StackPopJump::Normal)
     }
 
+    /// Turn an integer into a pointer. This is used by `force_ptr` whenever raw bits need to be
+    /// treated as a pointer, e.g. when dereferencing the result of an integer-to-pointer cast.
+    ///
+    /// Machines that support pointer-to-integer casts must make this the inverse of
+    /// `ptr_to_int`, so that a pointer round-tripped through `usize` still points into the
+    /// allocation it was derived from.
     fn int_to_ptr(
         _mem: &Memory<'mir, 'tcx, Self>,
         int: u64,
@@ -353,6 +415,8 @@ pub trait Machine<'mir, 'tcx>: Sized {
         .into())
     }
 
+    /// Turn a pointer into an integer. This is used by `force_bits` whenever a pointer needs to
+    /// be observed as raw bits, e.g. in `ptr as usize` casts and integer arithmetic on pointers.
     fn ptr_to_int(
         _mem: &Memory<'mir, 'tcx, Self>,
         _ptr: Pointer<Self::PointerTag>,
@@ -10,10 +10,11 @@ use std::borrow::Cow;
 use std::collections::VecDeque;
 use std::convert::{TryFrom, TryInto};
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::ptr;
 
 use rustc_ast::Mutability;
-use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet, FxHasher};
 use rustc_middle::ty::{Instance, ParamEnv, TyCtxt};
 use rustc_target::abi::{Align, HasDataLayout, Size, TargetDataLayout};
 
@@ -125,6 +126,22 @@ impl<'mir, 'tcx, M: Machine<'mir, 'tcx>> HasDataLayout for Memory<'mir, 'tcx, M>
     }
 }
 
+impl<'mir, 'tcx, M: Machine<'mir, 'tcx>> Clone for Memory<'mir, 'tcx, M>
+where
+    M::MemoryMap: Clone,
+    M::MemoryExtra: Clone,
+{
+    fn clone(&self) -> Self {
+        Memory {
+            alloc_map: self.alloc_map.clone(),
+            extra_fn_ptr_map: self.extra_fn_ptr_map.clone(),
+            dead_alloc_map: self.dead_alloc_map.clone(),
+            extra: self.extra.clone(),
+            tcx: self.tcx,
+        }
+    }
+}
+
 impl<'mir, 'tcx, M: Machine<'mir, 'tcx>> Memory<'mir, 'tcx, M> {
     pub fn new(tcx: TyCtxt<'tcx>, extra: M::MemoryExtra) -> Self {
         Memory {
@@ -433,13 +450,12 @@ impl<'mir, 'tcx, M: Machine<'mir, 'tcx>> Memory<'mir, 'tcx, M> {
     }
 
     /// Test if the pointer might be NULL.
-    pub fn ptr_may_be_null(&self, ptr: Pointer<M::PointerTag>) -> bool {
-        let (size, _align) = self
-            .get_size_and_align(ptr.alloc_id, AllocCheck::MaybeDead)
-            .expect("alloc info with MaybeDead cannot fail");
+    pub fn ptr_may_be_null(&self, ptr: Pointer<M::PointerTag>) -> InterpResult<'tcx, bool> {
+        // This can only fail if `ptr` points to a static whose layout cannot be computed.
+        let (size, _align) = self.get_size_and_align(ptr.alloc_id, AllocCheck::MaybeDead)?;
         // If the pointer is out-of-bounds, it may be null.
         // Note that one-past-the-end (offset == size) is still inbounds, and never null.
-        ptr.offset > size
+        Ok(ptr.offset > size)
     }
 }
 
@@ -576,7 +592,7 @@ impl<'mir, 'tcx, M: Machine<'mir, 'tcx>> Memory<'mir, 'tcx, M> {
         &self,
         id: AllocId,
         liveness: AllocCheck,
-    ) -> InterpResult<'static, (Size, Align)> {
+    ) -> InterpResult<'tcx, (Size, Align)> {
         // # Regular allocations
         // Don't use `self.get_raw` here as that will
         // a) cause cycles in case `id` refers to a static
@@ -604,7 +620,10 @@ impl<'mir, 'tcx, M: Machine<'mir, 'tcx>> Memory<'mir, 'tcx, M> {
                 assert!(!self.tcx.is_thread_local_static(did));
                 // Use size and align of the type.
                 let ty = self.tcx.type_of(did);
-                let layout = self.tcx.layout_of(ParamEnv::empty().and(ty)).unwrap();
+                let layout = self
+                    .tcx
+                    .layout_of(ParamEnv::empty().and(ty))
+                    .map_err(|layout| err_inval!(Layout(layout)))?;
                 Ok((layout.size, layout.align.abi))
             }
             Some(GlobalAlloc::Memory(alloc)) => {
@@ -674,27 +693,38 @@ impl<'mir, 'tcx, M: Machine<'mir, 'tcx>> Memory<'mir, 'tcx, M> {
         DumpAllocs { mem: self, allocs }
     }
 
+    /// Create a lazy debug printer for all interpreter-local allocations and everything they
+    /// point to, recursively.
+    #[must_use]
+    pub fn dump_all_allocs<'a>(&'a self) -> DumpAllocs<'a, 'mir, 'tcx, M> {
+        let allocs = self.alloc_map.filter_map_collect(|&id, _| Some(id));
+        self.dump_allocs(allocs)
+    }
+
+    /// Collect the set of allocations that are *reachable* from `static_roots` or a `Global`
+    /// allocation.
+    fn reachable_from_globals(&self, static_roots: &[AllocId]) -> FxHashSet<AllocId> {
+        let mut reachable = FxHashSet::default();
+        let global_kind = M::GLOBAL_KIND.map(MemoryKind::Machine);
+        let mut todo: Vec<_> = self.alloc_map.filter_map_collect(move |&id, &(kind, _)| {
+            if Some(kind) == global_kind { Some(id) } else { None }
+        });
+        todo.extend(static_roots);
+        while let Some(id) = todo.pop() {
+            if reachable.insert(id) {
+                // This is a new allocation, add its relocations to `todo`.
+                if let Some((_, alloc)) = self.alloc_map.get(id) {
+                    todo.extend(alloc.relocations().values().map(|&(_, target_id)| target_id));
+                }
+            }
+        }
+        reachable
+    }
+
     /// Print leaked memory. Allocations reachable from `static_roots` or a `Global` allocation
     /// are not considered leaked. Leaks whose kind `may_leak()` returns true are not reported.
     pub fn leak_report(&self, static_roots: &[AllocId]) -> usize {
-        // Collect the set of allocations that are *reachable* from `Global` allocations.
-        let reachable = {
-            let mut reachable = FxHashSet::default();
-            let global_kind = M::GLOBAL_KIND.map(MemoryKind::Machine);
-            let mut todo: Vec<_> = self.alloc_map.filter_map_collect(move |&id, &(kind, _)| {
-                if Some(kind) == global_kind { Some(id) } else { None }
-            });
-            todo.extend(static_roots);
-            while let Some(id) = todo.pop() {
-                if reachable.insert(id) {
-                    // This is a new allocation, add its relocations to `todo`.
-                    if let Some((_, alloc)) = self.alloc_map.get(id) {
-                        todo.extend(alloc.relocations().values().map(|&(_, target_id)| target_id));
-                    }
-                }
-            }
-            reachable
-        };
+        let reachable = self.reachable_from_globals(static_roots);
 
         // All allocations that are *not* `reachable` and *not* `may_leak` are considered leaking.
         let leaks: Vec<_> = self.alloc_map.filter_map_collect(|&id, &(kind, _)| {
@@ -707,12 +737,126 @@ impl<'mir, 'tcx, M: Machine<'mir, 'tcx>> Memory<'mir, 'tcx, M> {
         n
     }
 
+    /// Remove all allocations and function pointers that are not reachable from `static_roots`
+    /// or a `Global` allocation. This is used to reuse the same memory for another evaluation.
+    ///
+    /// The removed allocations are treated like deallocated ones, so any pointer to them that is
+    /// still around is reported as dangling when used.
+    pub fn clear_local_allocations(&mut self, static_roots: &[AllocId]) {
+        let reachable = self.reachable_from_globals(static_roots);
+        let local: Vec<_> = self.alloc_map.filter_map_collect(|&id, _| {
+            if reachable.contains(&id) { None } else { Some(id) }
+        });
+        for id in local {
+            let (_, alloc) = self.alloc_map.remove(&id).unwrap();
+            self.dead_alloc_map.insert(id, (alloc.size, alloc.align));
+        }
+        let local_fns: Vec<_> =
+            self.extra_fn_ptr_map.keys().filter(|id| !reachable.contains(id)).copied().collect();
+        for id in local_fns {
+            self.extra_fn_ptr_map.remove(&id);
+            self.dead_alloc_map.insert(id, (Size::ZERO, Align::from_bytes(1).unwrap()));
+        }
+    }
+
+    /// Compute a hash of the contents of all allocations reachable from `roots`, e.g. to compare
+    /// the final states of two evaluations. The hash does not depend on the concrete `AllocId`s:
+    /// allocations are numbered in the order in which they are first reached, and relocations
+    /// are hashed as the number of the allocation they point to. Uninitialized bytes all hash
+    /// the same, independent of their (meaningless) contents.
+    ///
+    /// This does not evaluate anything: statics that were never copied into this memory are
+    /// hashed by their path rather than by their contents.
+    pub fn state_hash(&self, roots: &[AllocId]) -> InterpResult<'tcx, u64> {
+        let mut hasher = FxHasher::default();
+        let mut numbering: FxHashMap<AllocId, usize> = FxHashMap::default();
+        let mut todo = VecDeque::new();
+        for &id in roots {
+            number_allocation(id, &mut numbering, &mut todo);
+        }
+        while let Some(id) = todo.pop_front() {
+            // Don't use `self.get_raw` here, as that may evaluate statics.
+            if let Some((_, alloc)) = self.alloc_map.get(id) {
+                hash_allocation(alloc, &mut hasher, &mut numbering, &mut todo);
+                continue;
+            }
+            if self.extra_fn_ptr_map.contains_key(&id) || self.dead_alloc_map.contains_key(&id) {
+                // Machine function pointers and dead allocations have no contents to hash.
+                StateHashKind::Opaque.hash(&mut hasher);
+                continue;
+            }
+            match self.tcx.get_global_alloc(id) {
+                Some(GlobalAlloc::Memory(alloc)) => {
+                    hash_allocation(alloc, &mut hasher, &mut numbering, &mut todo)
+                }
+                Some(GlobalAlloc::Static(def_id)) => {
+                    StateHashKind::Static.hash(&mut hasher);
+                    self.tcx.def_path_hash(def_id).hash(&mut hasher);
+                }
+                Some(GlobalAlloc::Function(instance)) => {
+                    StateHashKind::Function.hash(&mut hasher);
+                    instance.to_string().hash(&mut hasher);
+                }
+                None => throw_ub!(PointerUseAfterFree(id)),
+            }
+        }
+        Ok(hasher.finish())
+    }
+
     /// This is used by [priroda](https://github.com/oli-obk/priroda)
     pub fn alloc_map(&self) -> &M::MemoryMap {
         &self.alloc_map
     }
 }
 
+/// What `Memory::state_hash` found behind an `AllocId`.
+#[derive(Hash)]
+enum StateHashKind {
+    Allocation,
+    Static,
+    Function,
+    Opaque,
+}
+
+/// Numbers `id` for `Memory::state_hash`, queueing it for hashing when it is first reached.
+fn number_allocation(
+    id: AllocId,
+    numbering: &mut FxHashMap<AllocId, usize>,
+    todo: &mut VecDeque<AllocId>,
+) -> usize {
+    let next = numbering.len();
+    *numbering.entry(id).or_insert_with(|| {
+        todo.push_back(id);
+        next
+    })
+}
+
+/// Hashes the contents of `alloc` for `Memory::state_hash`. This is generic because global
+/// allocations have different associated types than the interpreter-local ones.
+fn hash_allocation<Tag, Extra>(
+    alloc: &Allocation<Tag, Extra>,
+    hasher: &mut FxHasher,
+    numbering: &mut FxHashMap<AllocId, usize>,
+    todo: &mut VecDeque<AllocId>,
+) {
+    StateHashKind::Allocation.hash(hasher);
+    alloc.len().hash(hasher);
+    alloc.align.hash(hasher);
+    alloc.mutability.hash(hasher);
+    let bytes = alloc.inspect_with_uninit_and_ptr_outside_interpreter(0..alloc.len());
+    for (i, &byte) in bytes.iter().enumerate() {
+        if alloc.init_mask().get(Size::from_bytes(i)) {
+            Some(byte).hash(hasher);
+        } else {
+            None::<u8>.hash(hasher);
+        }
+    }
+    for &(offset, (_, target_id)) in alloc.relocations().iter() {
+        offset.hash(hasher);
+        number_allocation(target_id, numbering, todo).hash(hasher);
+    }
+}
+
 #[doc(hidden)]
 /// There's no way to use this directly, it's just a helper struct for the `dump_alloc(s)` methods.
 pub struct DumpAllocs<'a, 'mir, 'tcx, M: Machine<'mir, 'tcx>> {
@@ -930,6 +1074,17 @@ impl<'mir, 'tcx, M: Machine<'mir, 'tcx>> Memory<'mir, 'tcx, M> {
         let dest_bytes =
             self.get_raw_mut(dest.alloc_id)?.get_bytes_mut(&tcx, dest, size * length)?; // `Size` multiplication
 
+        // Check for overlap first; the shortcuts below must not skip it.
+        if nonoverlapping && src.alloc_id == dest.alloc_id {
+            // The destination range covers all `length` copies. `Size` arithmetic.
+            let dest_size = size * length;
+            if (src.offset <= dest.offset && src.offset + size > dest.offset)
+                || (dest.offset <= src.offset && dest.offset + dest_size > src.offset)
+            {
+                throw_ub_format!("copy_nonoverlapping called on overlapping ranges")
+            }
+        }
+
         // If `dest_bytes` is empty we just optimize to not run anything for zsts.
         // See #67539
         if dest_bytes.is_empty() {
@@ -961,15 +1116,6 @@ impl<'mir, 'tcx, M: Machine<'mir, 'tcx>> Memory<'mir, 'tcx, M> {
         // point into the `Vec` storing the bytes.
         unsafe {
             if src.alloc_id == dest.alloc_id {
-                if nonoverlapping {
-                    // `Size` additions
-                    if (src.offset <= dest.offset && src.offset + size > dest.offset)
-                        || (dest.offset <= src.offset && dest.offset + size > src.offset)
-                    {
-                        throw_ub_format!("copy_nonoverlapping called on overlapping ranges")
-                    }
-                }
-
                 for i in 0..length {
                     ptr::copy(
                         src_bytes,
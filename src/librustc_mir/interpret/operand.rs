@@ -614,6 +614,13 @@ impl<'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
         // straight-forward (`TagEncoding::Direct`) or with a niche (`TagEncoding::Niche`).
         let (tag_scalar_layout, tag_encoding, tag_field) = match op.layout.variants {
             Variants::Single { index } => {
+                // Enums without variants still get a `Single` layout, but the variant it names
+                // does not exist. Any value of such a type is necessarily unreachable.
+                if let ty::Adt(adt, _) = op.layout.ty.kind {
+                    if adt.is_enum() && adt.variants.is_empty() {
+                        throw_ub!(Unreachable)
+                    }
+                }
                 let discr = match op.layout.ty.discriminant_for_variant(*self.tcx, index) {
                     Some(discr) => {
                         // This type actually has discriminants.
@@ -688,7 +695,7 @@ impl<'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
                         // The niche must be just 0 (which an inbounds pointer value never is)
                         let ptr_valid = niche_start == 0
                             && variants_start == variants_end
-                            && !self.memory.ptr_may_be_null(ptr);
+                            && !self.memory.ptr_may_be_null(ptr)?;
                         if !ptr_valid {
                             throw_ub!(InvalidTag(tag_val.erase_tag()))
                         }
@@ -43,6 +43,23 @@ impl<'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
         assert_eq!(ty, dest.layout.ty, "type mismatch for result of {:?}", op);
         self.write_scalar(val, dest)
     }
+
+    /// Applies the binary operation `op` to the arguments and writes the result to the
+    /// destination, raising an error if the operation overflowed.
+    pub fn binop_error_on_overflow(
+        &mut self,
+        op: mir::BinOp,
+        left: ImmTy<'tcx, M::PointerTag>,
+        right: ImmTy<'tcx, M::PointerTag>,
+        dest: PlaceTy<'tcx, M::PointerTag>,
+    ) -> InterpResult<'tcx> {
+        let (val, overflowed, ty) = self.overflowing_binary_op(op, left, right)?;
+        if overflowed {
+            throw_ub!(ArithOverflow { op });
+        }
+        assert_eq!(ty, dest.layout.ty, "type mismatch for result of {:?}", op);
+        self.write_scalar(val, dest)
+    }
 }
 
 impl<'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
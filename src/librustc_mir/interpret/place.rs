@@ -116,6 +116,19 @@ impl<'tcx, Tag> From<MPlaceTy<'tcx, Tag>> for PlaceTy<'tcx, Tag> {
     }
 }
 
+/// A host-side description of a value, used by embedders to inject data into interpreter
+/// memory with `InterpCx::allocate_serialized_value`. The shape of the value is given by
+/// the type it gets allocated at.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SerializedValue {
+    /// The raw bits of an integer, `bool`, `char` or float.
+    Scalar(u128),
+    /// The fields of a struct or tuple, or the elements of an array, in declaration order.
+    Aggregate(Vec<SerializedValue>),
+    /// A thin reference or raw pointer to another value, which gets its own allocation.
+    Ref(Box<SerializedValue>),
+}
+
 impl<Tag> MemPlace<Tag> {
     /// Replace ptr tag, maintain vtable tag (if any)
     #[inline]
@@ -322,6 +335,20 @@ where
         self.mplace_access_checked(place, None)
     }
 
+    /// Reads a value of type `ty` from memory at `ptr`, which must be aligned for `ty`.
+    /// This lets embedders inspect results without building places themselves; the returned
+    /// immediate can be turned into concrete values with `Scalar::to_i64`, `to_bool`, etc.
+    /// `ty` must have a scalar or scalar pair layout.
+    pub fn read_immediate_at(
+        &self,
+        ptr: Pointer<M::PointerTag>,
+        ty: Ty<'tcx>,
+    ) -> InterpResult<'tcx, ImmTy<'tcx, M::PointerTag>> {
+        let layout = self.layout_of(ty)?;
+        let place = self.mplace_access_checked(MPlaceTy::from_aligned_ptr(ptr, layout), None)?;
+        self.read_immediate(place.into())
+    }
+
     /// Check if the given place is good for memory access with the given
     /// size, falling back to the layout's size if `None` (in the latter case,
     /// this must be a statically sized type).
@@ -937,6 +964,14 @@ where
             "Cannot transmute unsized data"
         );
 
+        if dest.layout.is_zst() {
+            // There is no data to copy, but the target type might still be uninhabited.
+            if M::enforce_validity(self) {
+                self.validate_operand(self.place_to_op(dest)?)?;
+            }
+            return Ok(());
+        }
+
         // The hard case is `ScalarPair`.  `src` is already read from memory in this case,
         // using `src.layout` to figure out which bytes to use for the 1st and 2nd field.
         // We have to write them to `dest` at the offsets they were *read at*, which is
@@ -1046,6 +1081,77 @@ where
         MPlaceTy { mplace, layout }
     }
 
+    /// Allocates `value` at type `ty` and marks the allocation, as well as everything reachable
+    /// from it, as immutable. This is the inverse of reading a value back out of memory.
+    pub fn allocate_serialized_value(
+        &mut self,
+        value: &SerializedValue,
+        ty: Ty<'tcx>,
+        kind: MemoryKind<M::MemoryKind>,
+    ) -> InterpResult<'tcx, MPlaceTy<'tcx, M::PointerTag>> {
+        let layout = self.layout_of(ty)?;
+        if layout.is_unsized() {
+            throw_unsup_format!("cannot allocate a value of unsized type `{}`", ty);
+        }
+        let mplace = self.allocate(layout, kind);
+        self.write_serialized_value(value, mplace.into(), kind)?;
+        self.memory.mark_immutable(mplace.ptr.assert_ptr().alloc_id)?;
+        Ok(mplace)
+    }
+
+    fn write_serialized_value(
+        &mut self,
+        value: &SerializedValue,
+        dest: PlaceTy<'tcx, M::PointerTag>,
+        kind: MemoryKind<M::MemoryKind>,
+    ) -> InterpResult<'tcx> {
+        match *value {
+            SerializedValue::Scalar(bits) => {
+                if !matches!(dest.layout.abi, Abi::Scalar(_)) {
+                    throw_unsup_format!("cannot write a scalar to type `{}`", dest.layout.ty);
+                }
+                let scalar = Scalar::try_from_uint(bits, dest.layout.size).ok_or_else(|| {
+                    err_unsup_format!("value {:#x} does not fit into `{}`", bits, dest.layout.ty)
+                })?;
+                self.write_scalar(scalar, dest)?;
+            }
+            SerializedValue::Aggregate(ref fields) => {
+                let is_aggregate = match dest.layout.ty.kind {
+                    ty::Adt(adt_def, _) => adt_def.is_struct(),
+                    ty::Tuple(..) | ty::Array(..) => true,
+                    _ => false,
+                };
+                if !is_aggregate {
+                    throw_unsup_format!("cannot write an aggregate to type `{}`", dest.layout.ty);
+                }
+                let count = dest.layout.fields.count();
+                if fields.len() != count {
+                    throw_unsup_format!(
+                        "expected {} fields for `{}`, got {}",
+                        count,
+                        dest.layout.ty,
+                        fields.len()
+                    );
+                }
+                for (i, field) in fields.iter().enumerate() {
+                    let field_dest = self.place_field(dest, i)?;
+                    self.write_serialized_value(field, field_dest, kind)?;
+                }
+            }
+            SerializedValue::Ref(ref pointee) => {
+                let pointee_ty = match dest.layout.ty.kind {
+                    ty::Ref(_, ty, _) | ty::RawPtr(ty::TypeAndMut { ty, .. }) => ty,
+                    _ => {
+                        throw_unsup_format!("cannot write a reference to type `{}`", dest.layout.ty)
+                    }
+                };
+                let pointee = self.allocate_serialized_value(pointee, pointee_ty, kind)?;
+                self.write_immediate(pointee.to_ref(), dest)?;
+            }
+        }
+        Ok(())
+    }
+
     /// Writes the discriminant of the given variant.
     pub fn write_discriminant(
         &mut self,
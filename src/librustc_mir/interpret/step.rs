@@ -6,7 +6,7 @@ use rustc_middle::mir;
 use rustc_middle::mir::interpret::{InterpResult, Scalar};
 use rustc_target::abi::LayoutOf;
 
-use super::{InterpCx, Machine};
+use super::{InterpCx, Machine, OverflowMode};
 
 /// Classify whether an operator is "left-homogeneous", i.e., the LHS has the
 /// same type as the result.
@@ -61,6 +61,7 @@ impl<'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
         let old_frames = self.frame_idx();
 
         if let Some(stmt) = basic_block.statements.get(loc.statement_index) {
+            M::before_statement(self)?;
             assert_eq!(old_frames, self.frame_idx());
             self.statement(stmt)?;
             return Ok(true);
@@ -172,7 +173,10 @@ impl<'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
                 let left = self.read_immediate(self.eval_operand(left, layout)?)?;
                 let layout = binop_right_homogeneous(bin_op).then_some(left.layout);
                 let right = self.read_immediate(self.eval_operand(right, layout)?)?;
-                self.binop_ignore_overflow(bin_op, left, right, dest)?;
+                match M::overflow_mode(self) {
+                    OverflowMode::Wrap => self.binop_ignore_overflow(bin_op, left, right, dest)?,
+                    OverflowMode::Error => self.binop_error_on_overflow(bin_op, left, right, dest)?,
+                }
             }
 
             CheckedBinaryOp(bin_op, ref left, ref right) => {
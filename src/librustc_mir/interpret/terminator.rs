@@ -133,8 +133,10 @@ impl<'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
                 terminator.kind
             ),
 
-            // Inline assembly can't be interpreted.
-            InlineAsm { .. } => throw_unsup_format!("inline assembly is not supported"),
+            // Inline assembly can't be interpreted, but machines may emulate it.
+            InlineAsm { template, ref operands, options, line_spans: _, destination } => {
+                M::eval_inline_asm(self, template, operands, options, destination)?;
+            }
         }
 
         Ok(())
@@ -249,6 +251,19 @@ impl<'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
         match instance.def {
             ty::InstanceDef::Intrinsic(..) => {
                 assert!(caller_abi == Abi::RustIntrinsic || caller_abi == Abi::PlatformIntrinsic);
+                // Intrinsic implementations index into `args` directly. Typeck ensures that the
+                // caller passes as many arguments as the signature declares.
+                debug_assert_eq!(
+                    args.len(),
+                    instance
+                        .ty(*self.tcx, self.param_env)
+                        .fn_sig(*self.tcx)
+                        .inputs()
+                        .skip_binder()
+                        .len(),
+                    "wrong number of arguments for intrinsic `{}`",
+                    self.tcx.item_name(instance.def_id()),
+                );
                 M::call_intrinsic(self, instance, args, ret, unwind)
             }
             ty::InstanceDef::VtableShim(..)
@@ -412,7 +427,9 @@ impl<'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> InterpCx<'mir, 'tcx, M> {
                 args[0] =
                     OpTy::from(ImmTy::from_immediate(receiver_place.ptr.into(), this_receiver_ptr));
                 trace!("Patched self operand to {:#?}", args[0]);
-                // recurse with concrete function
+                // Recurse with concrete function. We keep passing the caller's return place,
+                // typed as seen from the call site; the return type check above makes sure the
+                // concrete callee agrees with it.
                 self.eval_fn_call(drop_fn, caller_abi, &args, ret, unwind)
             }
         }
@@ -605,7 +605,7 @@ impl<'rt, 'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> ValidityVisitor<'rt, 'mir, '
             Err(ptr) => {
                 if lo == 1 && hi == max_hi {
                     // Only NULL is the niche.  So make sure the ptr is NOT NULL.
-                    if self.ecx.memory.ptr_may_be_null(ptr) {
+                    if self.ecx.memory.ptr_may_be_null(ptr)? {
                         throw_validation_failure!(self.path,
                             { "a potentially NULL pointer" }
                             expected {
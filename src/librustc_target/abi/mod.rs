@@ -1104,12 +1104,14 @@ impl<'a, Ty> TyAndLayout<'a, Ty> {
     /// memory into an instance of `T`.
     /// `zero` indicates if the memory is zero-initialized, or alternatively
     /// left entirely uninitialized.
+    /// `strict` additionally descends into the element type of arrays
+    /// (`-Zstrict-init-checks`).
     /// This is conservative: in doubt, it will answer `true`.
     ///
     /// FIXME: Once we removed all the conservatism, we could alternatively
     /// create an all-0/all-undef constant and run the const value validator to see if
     /// this is a valid value for the given type.
-    pub fn might_permit_raw_init<C, E>(self, cx: &C, zero: bool) -> Result<bool, E>
+    pub fn might_permit_raw_init<C, E>(self, cx: &C, zero: bool, strict: bool) -> Result<bool, E>
     where
         Self: Copy,
         Ty: TyAndLayoutMethods<'a, C>,
@@ -1144,7 +1146,15 @@ impl<'a, Ty> TyAndLayout<'a, Ty> {
         }
 
         // If we have not found an error yet, we need to recursively descend.
-        // FIXME(#66151): For now, we are conservative and do not do this.
+        // FIXME(#66151): For now, we are conservative and do not do this, except for arrays
+        // (which are invalid as soon as their element type is) under `strict`.
+        if strict {
+            if let FieldsShape::Array { count, .. } = self.fields {
+                if count > 0 {
+                    return self.field(cx, 0).to_result()?.might_permit_raw_init(cx, zero, strict);
+                }
+            }
+        }
         Ok(true)
     }
 }
@@ -0,0 +1,441 @@
+// run-pass
+// Test the interpreter APIs meant for embedders: injecting values into memory and reading them
+// back, resetting a context to reuse it for another evaluation, hashing the state of memory,
+// taking and restoring snapshots, reporting unchecked arithmetic overflow, choosing whether
+// volatile accesses check alignment, and observing each statement.
+
+// ignore-cross-compile
+// ignore-stage1
+// ignore-remote
+
+#![feature(rustc_private, never_type)]
+
+#[macro_use]
+extern crate rustc_middle;
+extern crate rustc_driver;
+extern crate rustc_interface;
+extern crate rustc_mir;
+extern crate rustc_session;
+extern crate rustc_span;
+extern crate rustc_target;
+
+use rustc_interface::interface;
+use rustc_middle::mir::{
+    AssertMessage, BasicBlock, BinOp, Body, Local, Location, Place, START_BLOCK,
+};
+use rustc_middle::ty::{Instance, ParamEnv, Ty, TyCtxt};
+use rustc_mir::interpret::{
+    compile_time_machine, AllocCheck, Frame, ImmTy, Immediate, InterpCx, InterpError,
+    InterpResult, MPlaceTy, Machine, Memory, MemoryKind, OpTy, OverflowMode, PlaceTy, Pointer,
+    Scalar, SerializedValue, StackPopCleanup, UndefinedBehaviorInfo,
+};
+use rustc_session::config::{Input, Options};
+use rustc_session::DiagnosticOutput;
+use rustc_span::def_id::DefId;
+use rustc_span::source_map::FileName;
+use rustc_span::DUMMY_SP;
+use rustc_target::abi::{LayoutOf, Size};
+
+const SOURCE: &str = r#"
+#![feature(no_core, lang_items, intrinsics)]
+#![no_core]
+#![crate_type = "lib"]
+
+#[lang = "sized"]
+pub trait Sized {}
+#[lang = "copy"]
+pub trait Copy {}
+impl Copy for u32 {}
+#[lang = "add"]
+pub trait Add<Rhs = Self> {
+    type Output;
+    fn add(self, rhs: Rhs) -> Self::Output;
+}
+impl Add for u32 {
+    type Output = u32;
+    fn add(self, rhs: u32) -> u32 {
+        self + rhs
+    }
+}
+
+pub fn sum() -> u32 {
+    let values = [1u32, 2, 3];
+    let values = &values;
+    values[0] + values[1] + values[2]
+}
+
+pub fn sum_ref(values: &[u32; 3]) -> u32 {
+    values[0] + values[1] + values[2]
+}
+
+pub fn add_u32(a: u32, b: u32) -> u32 {
+    a + b
+}
+
+extern "rust-intrinsic" {
+    fn offset<T>(dst: *const T, offset: isize) -> *const T;
+    fn volatile_load<T>(src: *const T) -> T;
+}
+
+pub fn load_at(byte_offset: isize) -> u32 {
+    // Every byte is the same, so the result does not depend on endianness.
+    let data = [0x0101_0101u32, 0x0101_0101];
+    let bytes = &data as *const [u32; 2] as *const u8;
+    unsafe { volatile_load(offset(bytes, byte_offset) as *const u32) }
+}
+"#;
+
+/// Settings a test can change on the memory of a `TestMachine`.
+#[derive(Clone, Default)]
+struct TestMemoryExtra {
+    volatile_alignment: bool,
+}
+
+/// A machine that can only run plain, non-panicking code.
+#[derive(Clone)]
+struct TestMachine<'mir, 'tcx> {
+    stack: Vec<Frame<'mir, 'tcx>>,
+    overflow_mode: OverflowMode,
+    /// The location of every statement executed so far.
+    statements: Vec<Location>,
+}
+
+impl<'mir, 'tcx> Machine<'mir, 'tcx> for TestMachine<'mir, 'tcx> {
+    compile_time_machine!(<'mir, 'tcx>);
+
+    type MemoryExtra = TestMemoryExtra;
+
+    fn enforce_volatile_alignment(memory_extra: &TestMemoryExtra) -> bool {
+        memory_extra.volatile_alignment
+    }
+
+    fn before_statement(ecx: &mut InterpCx<'mir, 'tcx, Self>) -> InterpResult<'tcx> {
+        let loc = ecx.frame().loc.unwrap();
+        ecx.machine.statements.push(loc);
+        Ok(())
+    }
+
+    fn overflow_mode(ecx: &InterpCx<'mir, 'tcx, Self>) -> OverflowMode {
+        ecx.machine.overflow_mode
+    }
+
+    fn find_mir_or_eval_fn(
+        ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        instance: Instance<'tcx>,
+        _args: &[OpTy<'tcx>],
+        _ret: Option<(PlaceTy<'tcx>, BasicBlock)>,
+        _unwind: Option<BasicBlock>,
+    ) -> InterpResult<'tcx, Option<&'mir Body<'tcx>>> {
+        Ok(Some(ecx.load_mir(instance.def, None)?))
+    }
+
+    fn call_intrinsic(
+        ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        instance: Instance<'tcx>,
+        args: &[OpTy<'tcx>],
+        ret: Option<(PlaceTy<'tcx>, BasicBlock)>,
+        _unwind: Option<BasicBlock>,
+    ) -> InterpResult<'tcx> {
+        if ecx.emulate_intrinsic(instance, args, ret)? {
+            return Ok(());
+        }
+        throw_unsup_format!("only intrinsics the interpreter emulates are supported")
+    }
+
+    fn assert_panic(
+        _ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        _msg: &AssertMessage<'tcx>,
+        _unwind: Option<BasicBlock>,
+    ) -> InterpResult<'tcx> {
+        throw_unsup_format!("panics are not supported")
+    }
+
+    fn ptr_to_int(_mem: &Memory<'mir, 'tcx, Self>, _ptr: Pointer) -> InterpResult<'tcx, u64> {
+        throw_unsup_format!("pointer-to-integer casts are not supported")
+    }
+
+    fn binary_ptr_op(
+        _ecx: &InterpCx<'mir, 'tcx, Self>,
+        _bin_op: BinOp,
+        _left: ImmTy<'tcx>,
+        _right: ImmTy<'tcx>,
+    ) -> InterpResult<'tcx, (Scalar, bool, Ty<'tcx>)> {
+        throw_unsup_format!("pointer arithmetic is not supported")
+    }
+
+    fn box_alloc(
+        _ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        _dest: PlaceTy<'tcx>,
+    ) -> InterpResult<'tcx> {
+        throw_unsup_format!("heap allocations are not supported")
+    }
+
+    fn init_frame_extra(
+        _ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        frame: Frame<'mir, 'tcx>,
+    ) -> InterpResult<'tcx, Frame<'mir, 'tcx>> {
+        Ok(frame)
+    }
+
+    fn stack<'a>(ecx: &'a InterpCx<'mir, 'tcx, Self>) -> &'a [Frame<'mir, 'tcx>] {
+        &ecx.machine.stack
+    }
+
+    fn stack_mut<'a>(
+        ecx: &'a mut InterpCx<'mir, 'tcx, Self>,
+    ) -> &'a mut Vec<Frame<'mir, 'tcx>> {
+        &mut ecx.machine.stack
+    }
+}
+
+/// Pushes a frame calling `def_id` with `args`, whose locals will be left allocated, and returns
+/// the return place.
+fn start_call<'tcx>(
+    ecx: &mut InterpCx<'tcx, 'tcx, TestMachine<'tcx, 'tcx>>,
+    def_id: DefId,
+    args: &[Immediate],
+) -> InterpResult<'tcx, MPlaceTy<'tcx>> {
+    let instance = Instance::mono(*ecx.tcx, def_id);
+    let body = ecx.load_mir(instance.def, None)?;
+    let layout = ecx.layout_of(body.return_ty())?;
+    let ret = ecx.allocate(layout, MemoryKind::Stack);
+    let cleanup = StackPopCleanup::None { cleanup: false };
+    ecx.push_stack_frame(instance, body, Some(ret.into()), cleanup)?;
+    for (i, &arg) in args.iter().enumerate() {
+        let dest = ecx.eval_place(Place::from(Local::from_usize(i + 1)))?;
+        ecx.write_immediate(arg, dest)?;
+    }
+    Ok(ret)
+}
+
+/// Calls `def_id` with `args`, leaving its locals allocated, and returns the return place.
+fn eval<'tcx>(
+    ecx: &mut InterpCx<'tcx, 'tcx, TestMachine<'tcx, 'tcx>>,
+    def_id: DefId,
+    args: &[Immediate],
+) -> InterpResult<'tcx, MPlaceTy<'tcx>> {
+    let ret = start_call(ecx, def_id, args)?;
+    ecx.run()?;
+    Ok(ret)
+}
+
+/// Hashes all of memory.
+fn state_hash(ecx: &InterpCx<'_, '_, TestMachine<'_, '_>>) -> u64 {
+    // Allocations are made in a deterministic order, so sorting their `AllocId`s numbers them
+    // the same way for equal states.
+    let mut roots: Vec<_> = ecx.memory.alloc_map().keys().copied().collect();
+    roots.sort();
+    ecx.memory.state_hash(&roots).unwrap()
+}
+
+fn new_ecx<'tcx>(tcx: TyCtxt<'tcx>) -> InterpCx<'tcx, 'tcx, TestMachine<'tcx, 'tcx>> {
+    let machine = TestMachine {
+        stack: Vec::new(),
+        overflow_mode: OverflowMode::Wrap,
+        statements: Vec::new(),
+    };
+    InterpCx::new(tcx, DUMMY_SP, ParamEnv::reveal_all(), machine, TestMemoryExtra::default())
+}
+
+fn find_fn(tcx: TyCtxt<'_>, name: &str) -> DefId {
+    tcx.body_owners()
+        .find(|def_id| tcx.item_name(def_id.to_def_id()).as_str() == name)
+        .unwrap()
+        .to_def_id()
+}
+
+fn test_serialized_value(tcx: TyCtxt<'_>) {
+    let mut ecx = new_ecx(tcx);
+    let array_ty = tcx.mk_array(tcx.types.u32, 3);
+    let value = SerializedValue::Aggregate(vec![
+        SerializedValue::Scalar(1),
+        SerializedValue::Scalar(2),
+        SerializedValue::Scalar(3),
+    ]);
+    let values = ecx.allocate_serialized_value(&value, array_ty, MemoryKind::Stack).unwrap();
+    let ret = eval(&mut ecx, find_fn(tcx, "sum_ref"), &[values.to_ref()]).unwrap();
+    assert_eq!(ecx.read_scalar(ret.into()).unwrap().to_u32().unwrap(), 6);
+
+    // The value has to match the type it is allocated at.
+    let too_large = SerializedValue::Aggregate(vec![SerializedValue::Scalar(1 << 32); 3]);
+    assert!(ecx.allocate_serialized_value(&too_large, array_ty, MemoryKind::Stack).is_err());
+    let too_short = SerializedValue::Aggregate(vec![SerializedValue::Scalar(1); 2]);
+    assert!(ecx.allocate_serialized_value(&too_short, array_ty, MemoryKind::Stack).is_err());
+}
+
+fn test_read_immediate_at(tcx: TyCtxt<'_>) {
+    let mut ecx = new_ecx(tcx);
+    let array_ty = tcx.mk_array(tcx.types.u32, 3);
+    let value = SerializedValue::Aggregate(vec![
+        SerializedValue::Scalar(1),
+        SerializedValue::Scalar(2),
+        SerializedValue::Scalar(3),
+    ]);
+    let values = ecx.allocate_serialized_value(&value, array_ty, MemoryKind::Stack).unwrap();
+    let ptr = values.ptr.assert_ptr();
+    for i in 0..3 {
+        let elem = ptr.offset(Size::from_bytes(4 * i), &tcx).unwrap();
+        let imm = ecx.read_immediate_at(elem, tcx.types.u32).unwrap();
+        assert_eq!(imm.to_scalar().unwrap().to_u32().unwrap(), i as u32 + 1);
+    }
+
+    // Reading past the end of the allocation fails.
+    let past_end = ptr.offset(Size::from_bytes(12), &tcx).unwrap();
+    assert!(ecx.read_immediate_at(past_end, tcx.types.u32).is_err());
+}
+
+fn test_reset_and_state_hash(tcx: TyCtxt<'_>) {
+    let sum = find_fn(tcx, "sum");
+    let mut ecx = new_ecx(tcx);
+
+    // The return place and the local array are left behind.
+    let ret = eval(&mut ecx, sum, &[]).unwrap();
+    assert_eq!(ecx.read_scalar(ret.into()).unwrap().to_u32().unwrap(), 6);
+    let allocations = ecx.memory.alloc_map().len();
+    assert!(allocations >= 2);
+    let hash = state_hash(&ecx);
+
+    // Another context ends up in an equal state, even though it uses different `AllocId`s.
+    let mut other_ecx = new_ecx(tcx);
+    let other_ret = eval(&mut other_ecx, sum, &[]).unwrap();
+    assert_ne!(other_ret.ptr.assert_ptr().alloc_id, ret.ptr.assert_ptr().alloc_id);
+    assert_eq!(state_hash(&other_ecx), hash);
+    other_ecx.write_scalar(Scalar::from_u32(7), other_ret.into()).unwrap();
+    assert_ne!(state_hash(&other_ecx), hash);
+
+    // Roots survive a reset.
+    ecx.reset(&[ret.ptr.assert_ptr().alloc_id]);
+    assert_eq!(ecx.memory.alloc_map().len(), 1);
+    assert_eq!(ecx.read_scalar(ret.into()).unwrap().to_u32().unwrap(), 6);
+
+    // Everything else is gone, and pointers to it are dangling rather than unknown.
+    ecx.reset(&[]);
+    assert_eq!(ecx.memory.alloc_map().len(), 0);
+    assert!(ecx.read_scalar(ret.into()).is_err());
+    let ret_id = ret.ptr.assert_ptr().alloc_id;
+    assert!(ecx.memory.get_size_and_align(ret_id, AllocCheck::MaybeDead).is_ok());
+
+    // The context can be used for another evaluation.
+    let ret = eval(&mut ecx, sum, &[]).unwrap();
+    assert_eq!(ecx.read_scalar(ret.into()).unwrap().to_u32().unwrap(), 6);
+    assert_eq!(ecx.memory.alloc_map().len(), allocations);
+    assert_eq!(state_hash(&ecx), hash);
+}
+
+fn test_snapshot_and_restore(tcx: TyCtxt<'_>) {
+    let mut ecx = new_ecx(tcx);
+    let ret = eval(&mut ecx, find_fn(tcx, "sum"), &[]).unwrap();
+    let hash = state_hash(&ecx);
+
+    // Changes to memory are rolled back.
+    let snapshot = ecx.snapshot();
+    ecx.write_scalar(Scalar::from_u32(7), ret.into()).unwrap();
+    ecx.restore(snapshot);
+    assert_eq!(ecx.read_scalar(ret.into()).unwrap().to_u32().unwrap(), 6);
+    assert_eq!(state_hash(&ecx), hash);
+
+    // So is the stack, so a call can be run again from where the snapshot was taken.
+    let args: [Immediate; 2] = [Scalar::from_u32(1).into(), Scalar::from_u32(2).into()];
+    let ret = start_call(&mut ecx, find_fn(tcx, "add_u32"), &args).unwrap();
+    let snapshot = ecx.snapshot();
+    ecx.run().unwrap();
+    assert!(ecx.machine.stack.is_empty());
+    assert_eq!(ecx.read_scalar(ret.into()).unwrap().to_u32().unwrap(), 3);
+    ecx.restore(snapshot);
+    assert_eq!(ecx.machine.stack.len(), 1);
+    assert!(ecx.read_scalar(ret.into()).unwrap().to_u32().is_err());
+    ecx.run().unwrap();
+    assert_eq!(ecx.read_scalar(ret.into()).unwrap().to_u32().unwrap(), 3);
+}
+
+fn test_binop_overflow(tcx: TyCtxt<'_>) {
+    let add_u32 = find_fn(tcx, "add_u32");
+    let args: [Immediate; 2] = [Scalar::from_u32(u32::MAX).into(), Scalar::from_u32(2).into()];
+
+    // Without overflow checks, overflow wraps by default.
+    let mut ecx = new_ecx(tcx);
+    let ret = eval(&mut ecx, add_u32, &args).unwrap();
+    assert_eq!(ecx.read_scalar(ret.into()).unwrap().to_u32().unwrap(), 1);
+
+    // Machines can make it an error instead, which is distinct from unsupported operations.
+    let mut ecx = new_ecx(tcx);
+    ecx.machine.overflow_mode = OverflowMode::Error;
+    let err = eval(&mut ecx, add_u32, &args).unwrap_err();
+    assert!(matches!(
+        err.kind,
+        InterpError::UndefinedBehavior(UndefinedBehaviorInfo::ArithOverflow { op: BinOp::Add })
+    ));
+}
+
+fn test_volatile_alignment(tcx: TyCtxt<'_>) {
+    let load_at = find_fn(tcx, "load_at");
+    let aligned: [Immediate; 1] = [Scalar::from_machine_isize(4, &tcx).into()];
+    let misaligned: [Immediate; 1] = [Scalar::from_machine_isize(1, &tcx).into()];
+
+    // Like all other accesses, volatile ones are not checked for alignment by default.
+    let mut ecx = new_ecx(tcx);
+    let ret = eval(&mut ecx, load_at, &misaligned).unwrap();
+    assert_eq!(ecx.read_scalar(ret.into()).unwrap().to_u32().unwrap(), 0x0101_0101);
+
+    // Machines can check them separately.
+    let mut ecx = new_ecx(tcx);
+    ecx.memory.extra.volatile_alignment = true;
+    let ret = eval(&mut ecx, load_at, &aligned).unwrap();
+    assert_eq!(ecx.read_scalar(ret.into()).unwrap().to_u32().unwrap(), 0x0101_0101);
+    assert!(eval(&mut ecx, load_at, &misaligned).is_err());
+}
+
+fn test_before_statement(tcx: TyCtxt<'_>) {
+    let add_u32 = find_fn(tcx, "add_u32");
+    let args: [Immediate; 2] = [Scalar::from_u32(1).into(), Scalar::from_u32(2).into()];
+    let mut ecx = new_ecx(tcx);
+    eval(&mut ecx, add_u32, &args).unwrap();
+
+    // Without overflow checks, the body is a single block, so every statement runs once.
+    let body = ecx.load_mir(Instance::mono(tcx, add_u32).def, None).unwrap();
+    assert_eq!(body.basic_blocks().len(), 1);
+    let expected: Vec<_> = (0..body.basic_blocks()[START_BLOCK].statements.len())
+        .map(|statement_index| Location { block: START_BLOCK, statement_index })
+        .collect();
+    assert!(!expected.is_empty());
+    assert_eq!(ecx.machine.statements, expected);
+}
+
+fn main() {
+    let mut opts = Options::default();
+    // Keep the local array in the MIR.
+    opts.debugging_opts.mir_opt_level = 0;
+    // Emit unchecked arithmetic.
+    opts.cg.overflow_checks = Some(false);
+
+    let config = interface::Config {
+        opts,
+        crate_cfg: Default::default(),
+        input: Input::Str { name: FileName::Custom("test".to_string()), input: SOURCE.to_string() },
+        input_path: None,
+        output_file: None,
+        output_dir: None,
+        file_loader: None,
+        diagnostic_output: DiagnosticOutput::Default,
+        stderr: None,
+        crate_name: None,
+        lint_caps: Default::default(),
+        register_lints: None,
+        override_queries: None,
+        registry: rustc_driver::diagnostics_registry(),
+    };
+
+    interface::run_compiler(config, |compiler| {
+        compiler.enter(|queries| {
+            queries.global_ctxt().unwrap().peek_mut().enter(|tcx| {
+                test_serialized_value(tcx);
+                test_read_immediate_at(tcx);
+                test_reset_and_state_hash(tcx);
+                test_snapshot_and_restore(tcx);
+                test_binop_overflow(tcx);
+                test_volatile_alignment(tcx);
+                test_before_statement(tcx);
+            })
+        })
+    });
+}
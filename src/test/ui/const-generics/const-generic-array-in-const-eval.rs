@@ -0,0 +1,42 @@
+// Const-evaluating code that works on an array whose length is a const parameter.
+// The length has to be taken from the monomorphized substs both for layouts and for
+// indexing.
+
+// run-pass
+// revisions: full min
+
+#![cfg_attr(full, feature(const_generics))]
+#![cfg_attr(full, allow(incomplete_features))]
+#![cfg_attr(min, feature(min_const_generics))]
+
+use std::mem::size_of;
+
+struct Buf<const N: usize> {
+    data: [u8; N],
+}
+
+const fn filled<const N: usize>() -> Buf<N> {
+    let mut buf = Buf { data: [0; N] };
+    let mut i = 0;
+    while i < N {
+        buf.data[i] = i as u8 * 2;
+        i += 1;
+    }
+    buf
+}
+
+const fn last<const N: usize>(buf: &Buf<N>) -> u8 {
+    buf.data[buf.data.len() - 1]
+}
+
+const SIZE_16: usize = size_of::<Buf<16>>();
+const SIZE_3: usize = size_of::<Buf<3>>();
+const LAST_16: u8 = last(&filled::<16>());
+const LAST_3: u8 = last(&filled::<3>());
+
+fn main() {
+    assert_eq!(SIZE_16, 16);
+    assert_eq!(SIZE_3, 3);
+    assert_eq!(LAST_16, 30);
+    assert_eq!(LAST_3, 4);
+}
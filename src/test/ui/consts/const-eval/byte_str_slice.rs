@@ -0,0 +1,35 @@
+// run-pass
+
+// Byte string constants used as slices must carry their length.
+
+const ARRAY: &[u8; 3] = b"abc";
+const SLICE: &[u8] = b"abc";
+const EMPTY: &[u8] = b"";
+
+const LEN: usize = SLICE.len();
+const FIRST: u8 = SLICE[0];
+const LAST: u8 = SLICE[SLICE.len() - 1];
+const ARRAY_LAST: u8 = ARRAY[2];
+const EMPTY_LEN: usize = EMPTY.len();
+
+const fn sum(bytes: &[u8]) -> u32 {
+    let mut i = 0;
+    let mut sum = 0;
+    while i < bytes.len() {
+        sum += bytes[i] as u32;
+        i += 1;
+    }
+    sum
+}
+
+const SUM: u32 = sum(b"abc");
+
+fn main() {
+    assert_eq!(LEN, 3);
+    assert_eq!(FIRST, b'a');
+    assert_eq!(LAST, b'c');
+    assert_eq!(ARRAY_LAST, b'c');
+    assert_eq!(EMPTY_LEN, 0);
+    assert_eq!(SUM, 97 + 98 + 99);
+    assert_eq!(SLICE, ARRAY);
+}
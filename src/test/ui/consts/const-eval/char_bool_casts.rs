@@ -0,0 +1,41 @@
+// run-pass
+
+// `char` and `bool` can be cast to integers of any width during const evaluation.
+
+const fn digit_value(c: char) -> u32 {
+    c as u32 - '0' as u32
+}
+
+const fn count_true(flags: [bool; 4]) -> u8 {
+    flags[0] as u8 + flags[1] as u8 + flags[2] as u8 + flags[3] as u8
+}
+
+const A_U32: u32 = 'A' as u32;
+const A_U8: u8 = 'A' as u8;
+const SNOWMAN_U32: u32 = '☃' as u32;
+const SNOWMAN_U8: u8 = '☃' as u8;
+const MAX_CHAR_U64: u64 = std::char::MAX as u64;
+const MAX_CHAR_I16: i16 = std::char::MAX as i16;
+const FROM_U8: char = 0x61u8 as char;
+const DIGIT: u32 = digit_value('7');
+
+const TRUE_U8: u8 = true as u8;
+const FALSE_U64: u64 = false as u64;
+const TRUE_I128: i128 = true as i128;
+const TRUE_COUNT: u8 = count_true([true, false, true, true]);
+
+fn main() {
+    assert_eq!(A_U32, 65);
+    assert_eq!(A_U8, 65);
+    assert_eq!(SNOWMAN_U32, 0x2603);
+    assert_eq!(SNOWMAN_U8, 0x03);
+    assert_eq!(MAX_CHAR_U64, 0x10FFFF);
+    assert_eq!(MAX_CHAR_I16, -1);
+    assert_eq!(FROM_U8, 'a');
+    assert_eq!(DIGIT, 7);
+
+    assert_eq!(TRUE_U8, 1);
+    assert_eq!(FALSE_U64, 0);
+    assert_eq!(TRUE_I128, 1);
+    assert_eq!(TRUE_COUNT, 3);
+}
@@ -0,0 +1,32 @@
+// run-pass
+
+// `likely` and `unlikely` must pass their argument through unchanged.
+
+#![feature(core_intrinsics)]
+#![feature(const_likely)]
+
+use std::intrinsics::{likely, unlikely};
+
+const fn sign(x: i32) -> i32 {
+    if unsafe { likely(x > 0) } {
+        1
+    } else if unsafe { unlikely(x < 0) } {
+        -1
+    } else {
+        0
+    }
+}
+
+const POS: i32 = sign(5);
+const NEG: i32 = sign(-5);
+const ZERO: i32 = sign(0);
+const LIKELY: (bool, bool) = unsafe { (likely(true), likely(false)) };
+const UNLIKELY: (bool, bool) = unsafe { (unlikely(true), unlikely(false)) };
+
+fn main() {
+    assert_eq!(POS, 1);
+    assert_eq!(NEG, -1);
+    assert_eq!(ZERO, 0);
+    assert_eq!(LIKELY, (true, false));
+    assert_eq!(UNLIKELY, (true, false));
+}
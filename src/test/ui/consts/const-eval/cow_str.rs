@@ -0,0 +1,38 @@
+// run-pass
+
+// `Cow<str>` combines a wide pointer variant with an owned variant that has drop glue.
+// Both variants can be built and matched during const evaluation.
+
+#![feature(const_precise_live_drops)]
+
+use std::borrow::Cow;
+
+const fn with_len(c: Cow<'static, str>) -> (usize, Cow<'static, str>) {
+    let len = match &c {
+        Cow::Borrowed(s) => s.len(),
+        // `String::len` is not a const fn.
+        Cow::Owned(_) => usize::MAX,
+    };
+    (len, c)
+}
+
+const fn pick(borrowed: bool) -> Cow<'static, str> {
+    if borrowed { Cow::Borrowed("hi") } else { Cow::Owned(String::new()) }
+}
+
+const BORROWED: (usize, Cow<'static, str>) = with_len(pick(true));
+const OWNED: (usize, Cow<'static, str>) = with_len(pick(false));
+
+fn main() {
+    assert_eq!(BORROWED.0, 2);
+    match BORROWED.1 {
+        Cow::Borrowed(s) => assert_eq!(s, "hi"),
+        Cow::Owned(_) => panic!("expected a borrowed string"),
+    }
+
+    assert_eq!(OWNED.0, usize::MAX);
+    match OWNED.1 {
+        Cow::Owned(s) => assert!(s.is_empty()),
+        Cow::Borrowed(_) => panic!("expected an owned string"),
+    }
+}
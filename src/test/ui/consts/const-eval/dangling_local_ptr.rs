@@ -0,0 +1,15 @@
+#![feature(const_raw_ptr_deref)]
+
+// normalize-stderr-test "alloc\d+" -> "allocN"
+
+// Dereferencing a pointer to a local after its frame got popped is a use-after-free.
+
+const fn dangling() -> *const i32 {
+    let x = 42;
+    &x as *const i32
+}
+
+const USE_AFTER_FREE: i32 = unsafe { *dangling() };
+//~^ ERROR any use of this value will cause an error
+
+fn main() {}
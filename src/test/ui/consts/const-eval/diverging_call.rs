@@ -0,0 +1,33 @@
+// run-pass
+
+// Calls to diverging functions have no return place. Evaluating a function that contains such
+// calls must work as long as the diverging call itself is not reached.
+
+#![feature(const_panic)]
+
+const fn diverge(msg: &'static str) -> ! {
+    panic!(msg)
+}
+
+const fn nonzero(x: u32) -> u32 {
+    if x == 0 {
+        diverge("zero");
+    }
+    x
+}
+
+const fn pick(a: Option<u32>) -> u32 {
+    let v: u32 = match a {
+        Some(v) => v,
+        None => diverge("none"),
+    };
+    v * 2
+}
+
+const NONZERO: u32 = nonzero(7);
+const PICKED: u32 = pick(Some(21));
+
+fn main() {
+    assert_eq!(NONZERO, 7);
+    assert_eq!(PICKED, 42);
+}
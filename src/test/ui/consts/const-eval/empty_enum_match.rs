@@ -0,0 +1,16 @@
+// Matching on or reading the discriminant of a value of an empty enum is unreachable.
+
+#![feature(const_raw_ptr_deref)]
+#![feature(core_intrinsics)]
+
+use std::intrinsics::discriminant_value;
+
+enum Void {}
+
+const MATCH: () = unsafe { match *(&() as *const () as *const Void) {} };
+//~^ ERROR any use of this value will cause an error
+
+const DISCR: () = unsafe { let _ = discriminant_value(&*(&() as *const () as *const Void)); };
+//~^ ERROR any use of this value will cause an error
+
+fn main() {}
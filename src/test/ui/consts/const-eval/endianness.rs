@@ -0,0 +1,21 @@
+// run-pass
+
+// Integers are laid out in memory using the byte order of the target, not of the host.
+
+const BYTES: [u8; 4] = unsafe { std::mem::transmute(0x1234_5678_u32) };
+const FROM_BYTES: u32 = unsafe { std::mem::transmute([0x12_u8, 0x34, 0x56, 0x78]) };
+const HALVES: [u16; 2] = unsafe { std::mem::transmute(0x1234_5678_u32) };
+
+#[cfg(target_endian = "little")]
+fn main() {
+    assert_eq!(BYTES, [0x78, 0x56, 0x34, 0x12]);
+    assert_eq!(FROM_BYTES, 0x7856_3412);
+    assert_eq!(HALVES, [0x5678, 0x1234]);
+}
+
+#[cfg(target_endian = "big")]
+fn main() {
+    assert_eq!(BYTES, [0x12, 0x34, 0x56, 0x78]);
+    assert_eq!(FROM_BYTES, 0x1234_5678);
+    assert_eq!(HALVES, [0x1234, 0x5678]);
+}
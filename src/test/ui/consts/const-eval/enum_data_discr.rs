@@ -0,0 +1,78 @@
+// run-pass
+
+// Reading the discriminant of enums with fields works for both tagged and niche-encoded layouts.
+
+#![feature(const_discriminant)]
+#![feature(core_intrinsics)]
+
+use std::intrinsics::discriminant_value;
+
+// Uses a direct tag next to the payload.
+enum Tagged {
+    A(u32),
+    B { x: u8, y: u64 },
+    C,
+}
+
+// Uses the niche in the `bool` field to encode the other variants.
+enum Niche {
+    Data(bool),
+    Empty,
+    Other,
+}
+
+const fn tagged_kind(t: &Tagged) -> u8 {
+    match t {
+        Tagged::A(_) => 0,
+        Tagged::B { .. } => 1,
+        Tagged::C => 2,
+    }
+}
+
+const fn niche_kind(n: &Niche) -> u8 {
+    match n {
+        Niche::Data(true) => 0,
+        Niche::Data(false) => 1,
+        Niche::Empty => 2,
+        Niche::Other => 3,
+    }
+}
+
+const fn is_some(r: Option<&u8>) -> bool {
+    match r {
+        Some(_) => true,
+        None => false,
+    }
+}
+
+const TAGGED_A: u8 = tagged_kind(&Tagged::A(17));
+const TAGGED_B: u8 = tagged_kind(&Tagged::B { x: 1, y: 2 });
+const TAGGED_C: u8 = tagged_kind(&Tagged::C);
+const TAGGED_B_DISCR: isize = unsafe { discriminant_value(&Tagged::B { x: 1, y: 2 }) };
+
+const NICHE_TRUE: u8 = niche_kind(&Niche::Data(true));
+const NICHE_FALSE: u8 = niche_kind(&Niche::Data(false));
+const NICHE_EMPTY: u8 = niche_kind(&Niche::Empty);
+const NICHE_OTHER: u8 = niche_kind(&Niche::Other);
+const NICHE_OTHER_DISCR: isize = unsafe { discriminant_value(&Niche::Other) };
+
+const SOME_REF: bool = is_some(Some(&5));
+const NONE_REF: bool = is_some(None);
+const NONE_REF_DISCR: isize = unsafe { discriminant_value(&None::<&u8>) };
+
+fn main() {
+    assert_eq!(TAGGED_A, 0);
+    assert_eq!(TAGGED_B, 1);
+    assert_eq!(TAGGED_C, 2);
+    assert_eq!(TAGGED_B_DISCR, 1);
+
+    assert_eq!(NICHE_TRUE, 0);
+    assert_eq!(NICHE_FALSE, 1);
+    assert_eq!(NICHE_EMPTY, 2);
+    assert_eq!(NICHE_OTHER, 3);
+    assert_eq!(NICHE_OTHER_DISCR, 2);
+
+    assert!(SOME_REF);
+    assert!(!NONE_REF);
+    assert_eq!(NONE_REF_DISCR, 0);
+}
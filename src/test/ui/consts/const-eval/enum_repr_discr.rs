@@ -0,0 +1,60 @@
+// run-pass
+
+// Enums with an explicit integer `repr` store and read their discriminant using that width.
+
+use std::mem;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(u16)]
+enum Wide {
+    A = 300,
+    B = 0xfffe,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(i8)]
+enum Signed {
+    Neg = -100,
+    Pos = 100,
+}
+
+const fn is_b(w: Wide) -> bool {
+    match w {
+        Wide::A => false,
+        Wide::B => true,
+    }
+}
+
+const fn is_neg(s: Signed) -> bool {
+    match s {
+        Signed::Neg => true,
+        Signed::Pos => false,
+    }
+}
+
+const A_BITS: u16 = unsafe { mem::transmute(Wide::A) };
+const B_BITS: u16 = unsafe { mem::transmute(Wide::B) };
+const FROM_BITS: Wide = unsafe { mem::transmute(0xfffe_u16) };
+const NEG_BITS: i8 = unsafe { mem::transmute(Signed::Neg) };
+const FROM_NEG_BITS: Signed = unsafe { mem::transmute(-100_i8) };
+
+const A_IS_B: bool = is_b(Wide::A);
+const FROM_BITS_IS_B: bool = is_b(FROM_BITS);
+const FROM_NEG_BITS_IS_NEG: bool = is_neg(FROM_NEG_BITS);
+
+fn main() {
+    assert_eq!(mem::size_of::<Wide>(), 2);
+    assert_eq!(mem::size_of::<Signed>(), 1);
+
+    assert_eq!(A_BITS, 300);
+    assert_eq!(B_BITS, 0xfffe);
+    assert_eq!(FROM_BITS, Wide::B);
+    assert_eq!(NEG_BITS, -100);
+    assert_eq!(FROM_NEG_BITS, Signed::Neg);
+
+    assert!(!A_IS_B);
+    assert!(FROM_BITS_IS_B);
+    assert!(FROM_NEG_BITS_IS_NEG);
+    assert_eq!(Wide::A as u16, 300);
+    assert_eq!(Signed::Neg as i8, -100);
+}
@@ -0,0 +1,39 @@
+// run-pass
+
+// Comparisons involving NaN follow IEEE semantics: everything is false except `!=`.
+
+const NAN_EQ_NAN: bool = f32::NAN == f32::NAN;
+const NAN_NE_NAN: bool = f32::NAN != f32::NAN;
+const NAN_LT_ONE: bool = f32::NAN < 1.0;
+const NAN_LE_ONE: bool = f32::NAN <= 1.0;
+const NAN_GT_ONE: bool = f32::NAN > 1.0;
+const NAN_GE_ONE: bool = f32::NAN >= 1.0;
+const ONE_LT_NAN: bool = 1.0 < f32::NAN;
+
+const NAN64_EQ_NAN: bool = f64::NAN == f64::NAN;
+const NAN64_NE_NAN: bool = f64::NAN != f64::NAN;
+const NAN64_LT_ONE: bool = f64::NAN < 1.0;
+const NAN64_GE_INF: bool = f64::NAN >= f64::NEG_INFINITY;
+const NAN64_NE_ONE: bool = f64::NAN != 1.0;
+
+const ZERO_EQ_NEG_ZERO: bool = 0.0f64 == -0.0;
+const NEG_ZERO_LT_ZERO: bool = -0.0f32 < 0.0;
+
+fn main() {
+    assert!(!NAN_EQ_NAN);
+    assert!(NAN_NE_NAN);
+    assert!(!NAN_LT_ONE);
+    assert!(!NAN_LE_ONE);
+    assert!(!NAN_GT_ONE);
+    assert!(!NAN_GE_ONE);
+    assert!(!ONE_LT_NAN);
+
+    assert!(!NAN64_EQ_NAN);
+    assert!(NAN64_NE_NAN);
+    assert!(!NAN64_LT_ONE);
+    assert!(!NAN64_GE_INF);
+    assert!(NAN64_NE_ONE);
+
+    assert!(ZERO_EQ_NEG_ZERO);
+    assert!(!NEG_ZERO_LT_ZERO);
+}
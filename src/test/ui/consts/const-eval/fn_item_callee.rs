@@ -0,0 +1,53 @@
+// run-pass
+
+// Calls whose callee is a zero-sized fn item value (rather than a fn pointer)
+// must be resolved from the callee's type, not from memory.
+
+const fn double(x: usize) -> usize {
+    x * 2
+}
+
+const fn call_via_local(x: usize) -> usize {
+    let f = double;
+    f(x)
+}
+
+const fn call_via_tuple(x: usize) -> usize {
+    let pair = (double, x);
+    (pair.0)(pair.1)
+}
+
+struct Holder<F> {
+    f: F,
+    arg: usize,
+}
+
+const fn call_via_struct(x: usize) -> usize {
+    let holder = Holder { f: double, arg: x };
+    (holder.f)(holder.arg)
+}
+
+const fn call_via_field_copy(x: usize) -> usize {
+    let holder = Holder { f: double, arg: x };
+    let f = holder.f;
+    f(holder.arg)
+}
+
+const fn call_via_ref(x: usize) -> usize {
+    let r = &double;
+    (*r)(x)
+}
+
+const A: usize = call_via_local(21);
+const B: usize = call_via_tuple(21);
+const C: usize = call_via_struct(21);
+const D: usize = call_via_field_copy(21);
+const E: usize = call_via_ref(21);
+
+fn main() {
+    assert_eq!(A, 42);
+    assert_eq!(B, 42);
+    assert_eq!(C, 42);
+    assert_eq!(D, 42);
+    assert_eq!(E, 42);
+}
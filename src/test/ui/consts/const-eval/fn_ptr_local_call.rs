@@ -0,0 +1,28 @@
+// Calling through a `fn` pointer stored in a local resolves the callee from the
+// pointer's allocation, even after the local has been reassigned.
+
+// run-pass
+// compile-flags: -Zunleash-the-miri-inside-of-you
+
+#![feature(const_fn)]
+
+const fn add_one(x: i32) -> i32 {
+    x + 1
+}
+
+const fn negate(x: i32) -> i32 {
+    -x
+}
+
+const fn apply_both(x: i32) -> i32 {
+    let mut f: fn(i32) -> i32 = add_one;
+    let y = f(x);
+    f = negate;
+    f(y)
+}
+
+const RESULT: i32 = apply_both(41);
+
+fn main() {
+    assert_eq!(RESULT, -42);
+}
@@ -0,0 +1,45 @@
+// run-pass
+
+// Associated constants reached through a generic parameter are evaluated with the
+// monomorphized substs of the calling frame.
+
+#![feature(const_fn)]
+
+trait Bounded {
+    const MAX: u64;
+    const NAME: &'static str;
+}
+
+impl Bounded for u8 {
+    const MAX: u64 = u8::MAX as u64;
+    const NAME: &'static str = "u8";
+}
+
+impl Bounded for u16 {
+    const MAX: u64 = u16::MAX as u64;
+    const NAME: &'static str = "u16";
+}
+
+const fn max_of<T: Bounded>() -> u64 {
+    T::MAX
+}
+
+const fn sum_of_max<T: Bounded, U: Bounded>() -> u64 {
+    max_of::<T>() + <U as Bounded>::MAX
+}
+
+const fn name_len<T: Bounded>() -> usize {
+    T::NAME.len()
+}
+
+const MAX_U8: u64 = max_of::<u8>();
+const MAX_U16: u64 = max_of::<u16>();
+const SUM: u64 = sum_of_max::<u8, u16>();
+const NAME_LEN_U16: usize = name_len::<u16>();
+
+fn main() {
+    assert_eq!(MAX_U8, 255);
+    assert_eq!(MAX_U16, 65535);
+    assert_eq!(SUM, 255 + 65535);
+    assert_eq!(NAME_LEN_U16, 3);
+}
@@ -0,0 +1,35 @@
+// run-pass
+
+// The interpreter sees through opaque return types to their hidden type when computing layouts.
+// Closures cannot be called during const evaluation, so this sticks to plain data.
+
+#![feature(const_fn)]
+#![feature(const_size_of_val)]
+
+#[derive(Copy, Clone)]
+struct Pair(u8, u64);
+
+const fn opaque_int() -> impl Copy {
+    42u32
+}
+
+const fn opaque_pair() -> impl Copy {
+    Pair(1, 2)
+}
+
+const fn wrap() -> (impl Copy, u32, impl Copy) {
+    (opaque_pair(), 7, opaque_int())
+}
+
+const fn middle() -> u32 {
+    let (_, x, _) = wrap();
+    x
+}
+
+const MIDDLE: u32 = middle();
+const SIZE: usize = std::mem::size_of_val(&opaque_pair());
+
+fn main() {
+    assert_eq!(MIDDLE, 7);
+    assert_eq!(SIZE, std::mem::size_of::<Pair>());
+}
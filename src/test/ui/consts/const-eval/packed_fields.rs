@@ -0,0 +1,54 @@
+// run-pass
+
+// Fields of packed structs live at unaligned offsets. Reading and writing them by value
+// must not trip alignment checks.
+
+#[repr(packed)]
+#[derive(Copy, Clone)]
+struct Packed {
+    a: u8,
+    b: u32,
+    c: u16,
+    d: u64,
+}
+
+#[repr(C, packed(2))]
+#[derive(Copy, Clone)]
+struct Packed2 {
+    a: u8,
+    b: u64,
+}
+
+const fn bump(mut p: Packed) -> Packed {
+    p.b += 1;
+    p.c = p.c * 2;
+    p.d = p.b as u64 + p.c as u64;
+    p
+}
+
+const fn sum(p: Packed) -> u64 {
+    let b = p.b;
+    let d = p.d;
+    p.a as u64 + b as u64 + p.c as u64 + d
+}
+
+const BUMPED: Packed = bump(Packed { a: 1, b: 41, c: 8, d: 0 });
+const SUM: u64 = sum(BUMPED);
+const BYTES: [u8; 15] = unsafe { std::mem::transmute(BUMPED) };
+const P2_B: u64 = {
+    let mut p = Packed2 { a: 0, b: 0 };
+    p.b = 0x0102_0304_0506_0708;
+    p.b
+};
+
+fn main() {
+    assert_eq!(std::mem::size_of::<Packed>(), 15);
+    let b = BUMPED.b;
+    let c = BUMPED.c;
+    let d = BUMPED.d;
+    assert_eq!((BUMPED.a, b, c, d), (1, 42, 16, 58));
+    assert_eq!(SUM, 1 + 42 + 16 + 58);
+    assert_eq!(BYTES[0], 1);
+    assert_eq!(u32::from_ne_bytes([BYTES[1], BYTES[2], BYTES[3], BYTES[4]]), 42);
+    assert_eq!(P2_B, 0x0102_0304_0506_0708);
+}
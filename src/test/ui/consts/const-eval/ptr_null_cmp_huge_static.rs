@@ -0,0 +1,18 @@
+// only-x86_64
+
+// Comparing a pointer to a static with null needs the size of the static. If its layout cannot
+// be computed, that has to be reported as an error rather than an ICE.
+
+#![feature(core_intrinsics, const_raw_ptr_comparison)]
+
+use std::intrinsics::ptr_guaranteed_ne;
+
+const HUGE_SIZE: usize = !0usize / 8;
+
+static HUGE: [u8; HUGE_SIZE] = [0x00; HUGE_SIZE];
+//~^ ERROR the type `[u8; 2305843009213693951]` is too big for the current architecture
+
+static NOT_NULL: bool = unsafe { ptr_guaranteed_ne(&HUGE as *const _, std::ptr::null()) };
+//~^ ERROR the type `[u8; 2305843009213693951]` is too big for the current architecture
+
+fn main() {}
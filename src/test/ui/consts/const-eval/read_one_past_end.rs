@@ -0,0 +1,16 @@
+// Pointers one past the end of an allocation may be formed, but not read from.
+
+#![feature(const_ptr_offset)]
+#![feature(const_raw_ptr_deref)]
+
+// normalize-stderr-test "alloc\d+" -> "allocN"
+
+const ARRAY: [u8; 3] = [1, 2, 3];
+
+const LAST: u8 = unsafe { *ARRAY.as_ptr().add(2) };
+const END: *const u8 = unsafe { ARRAY.as_ptr().add(3) };
+const READ_END: u8 = unsafe { *ARRAY.as_ptr().add(3) }; //~ ERROR any use of this value will cause an error
+
+fn main() {
+    let _ = (LAST, END);
+}
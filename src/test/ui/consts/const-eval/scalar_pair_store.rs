@@ -0,0 +1,42 @@
+// run-pass
+
+// Two-scalar aggregates are passed around as immediate pairs and must be written to memory with
+// the second scalar at its field offset, including when there is padding between the two.
+
+const fn pair(a: u64, b: u64) -> (u64, u64) {
+    (a, b)
+}
+
+const fn padded(a: u8, b: u32) -> (u8, u32) {
+    (a, b)
+}
+
+const fn with_flag(x: u16) -> (bool, u16) {
+    (x > 10, x)
+}
+
+struct Store {
+    pairs: [(u64, u64); 2],
+    padded: (u8, u32),
+    flagged: Option<(bool, u16)>,
+}
+
+const STORE: Store = Store {
+    pairs: [pair(1, 2), pair(u64::MAX, 3)],
+    padded: padded(0xab, 0x0102_0304),
+    flagged: Some(with_flag(42)),
+};
+
+const SWAPPED: (u64, u64) = {
+    let p = pair(5, 6);
+    let mut arr = [p, p];
+    arr[1] = (p.1, p.0);
+    arr[1]
+};
+
+fn main() {
+    assert_eq!(STORE.pairs, [(1, 2), (u64::MAX, 3)]);
+    assert_eq!(STORE.padded, (0xab, 0x0102_0304));
+    assert_eq!(STORE.flagged, Some((true, 42)));
+    assert_eq!(SWAPPED, (6, 5));
+}
@@ -0,0 +1,56 @@
+// run-pass
+
+// Shift-and-mask bit extraction, as used when parsing binary formats. Right shifts of signed
+// values are arithmetic (sign-extending), right shifts of unsigned values are logical.
+
+const fn nibbles(x: u8) -> (u8, u8) {
+    ((x >> 4) & 0xF, x & 0xF)
+}
+
+const fn field_u16(x: u16, shift: u32, bits: u32) -> u16 {
+    (x >> shift) & ((1 << bits) - 1)
+}
+
+const fn field_u32(x: u32, shift: u32, bits: u32) -> u32 {
+    (x >> shift) & ((1 << bits) - 1)
+}
+
+const fn field_u64(x: u64, shift: u32, bits: u32) -> u64 {
+    (x >> shift) & ((1 << bits) - 1)
+}
+
+const fn pack(a: u8, b: u8, c: u16) -> u32 {
+    (a as u32) << 24 | (b as u32) << 16 | c as u32
+}
+
+const NIBBLES: (u8, u8) = nibbles(0xA7);
+const FIELD_U16: u16 = field_u16(0b1011_0110_0000_0000, 9, 3);
+const FIELD_U32: u32 = field_u32(0xDEAD_BEEF, 12, 8);
+const FIELD_U64: u64 = field_u64(0x0123_4567_89AB_CDEF, 40, 16);
+const PACKED: u32 = pack(0x12, 0x34, 0x5678);
+const UNPACKED: (u32, u32, u32) = (PACKED >> 24, (PACKED >> 16) & 0xFF, PACKED & 0xFFFF);
+
+const NEG_I8_SHR: i8 = -8i8 >> 1;
+const U8_SHR: u8 = 0xF8u8 >> 1;
+const NEG_I16_SHR: i16 = i16::MIN >> 15;
+const NEG_I32_SHR: i32 = -1i32 >> 31;
+const NEG_I64_SHR: i64 = -256i64 >> 4;
+const NEG_I32_MASK: i32 = (-0x100 >> 4) & 0xFF;
+const U64_SHR: u64 = u64::MAX >> 63;
+
+fn main() {
+    assert_eq!(NIBBLES, (0xA, 0x7));
+    assert_eq!(FIELD_U16, 0b011);
+    assert_eq!(FIELD_U32, 0xDB);
+    assert_eq!(FIELD_U64, 0x2345);
+    assert_eq!(PACKED, 0x1234_5678);
+    assert_eq!(UNPACKED, (0x12, 0x34, 0x5678));
+
+    assert_eq!(NEG_I8_SHR, -4);
+    assert_eq!(U8_SHR, 0x7C);
+    assert_eq!(NEG_I16_SHR, -1);
+    assert_eq!(NEG_I32_SHR, -1);
+    assert_eq!(NEG_I64_SHR, -16);
+    assert_eq!(NEG_I32_MASK, 0xF0);
+    assert_eq!(U64_SHR, 1);
+}
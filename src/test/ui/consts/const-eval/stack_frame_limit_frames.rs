@@ -0,0 +1,24 @@
+// When the stack frame limit is hit, runs of identical consecutive frames are collapsed, but
+// frames that alternate are all listed.
+
+#![recursion_limit = "10"]
+#![allow(unconditional_recursion)]
+
+const fn recurse() -> u32 {
+    recurse()
+    //~^ ERROR could not evaluate static initializer
+}
+
+const fn ping() -> u32 {
+    pong()
+    //~^ ERROR could not evaluate static initializer
+}
+
+const fn pong() -> u32 {
+    ping()
+}
+
+static DIRECT: u32 = recurse();
+static ALTERNATING: u32 = ping();
+
+fn main() {}
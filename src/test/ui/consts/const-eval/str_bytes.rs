@@ -0,0 +1,42 @@
+// run-pass
+
+// Indexing into and sub-slicing the bytes of a `str` during const evaluation.
+
+const S: &str = "h\u{e9}llo";
+const BYTES: &[u8] = S.as_bytes();
+
+const FIRST: u8 = BYTES[0];
+const LAST: u8 = BYTES[BYTES.len() - 1];
+// `é` is encoded as two bytes.
+const E_ACUTE: [u8; 2] = [BYTES[1], BYTES[2]];
+
+const fn tail(bytes: &[u8]) -> &[u8] {
+    match bytes {
+        [_, _, _, rest @ ..] => rest,
+        _ => &[],
+    }
+}
+
+const fn count_ascii(bytes: &[u8]) -> usize {
+    let mut i = 0;
+    let mut n = 0;
+    while i < bytes.len() {
+        if bytes[i] < 0x80 {
+            n += 1;
+        }
+        i += 1;
+    }
+    n
+}
+
+const TAIL: &[u8] = tail(BYTES);
+const ASCII: usize = count_ascii(BYTES);
+
+fn main() {
+    assert_eq!(BYTES.len(), 6);
+    assert_eq!(FIRST, b'h');
+    assert_eq!(LAST, b'o');
+    assert_eq!(E_ACUTE, [0xc3, 0xa9]);
+    assert_eq!(TAIL, b"llo");
+    assert_eq!(ASCII, 4);
+}
@@ -0,0 +1,25 @@
+// run-pass
+
+// Casting between thin raw pointers keeps the pointer, including the allocation it points into.
+
+#![feature(const_raw_ptr_deref)]
+
+const VALUE: u32 = 0x0403_0201;
+
+const FIRST_BYTE: u8 = unsafe { *(&VALUE as *const u32 as *const u8) };
+const LOW_HALF: u16 = unsafe { *(&VALUE as *const u32 as *const u16) };
+const ROUND_TRIP: u32 = unsafe { *(&VALUE as *const u32 as *const u8 as *const u32) };
+
+#[cfg(target_endian = "little")]
+fn main() {
+    assert_eq!(FIRST_BYTE, 0x01);
+    assert_eq!(LOW_HALF, 0x0201);
+    assert_eq!(ROUND_TRIP, VALUE);
+}
+
+#[cfg(target_endian = "big")]
+fn main() {
+    assert_eq!(FIRST_BYTE, 0x04);
+    assert_eq!(LOW_HALF, 0x0403);
+    assert_eq!(ROUND_TRIP, VALUE);
+}
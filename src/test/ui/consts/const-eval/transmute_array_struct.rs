@@ -0,0 +1,35 @@
+// run-pass
+
+// Transmuting an array into a struct with the same layout reinterprets the bytes field by
+// field, keeping any pointers intact.
+
+use std::mem::transmute;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct Refs {
+    first: &'static u8,
+    second: &'static u8,
+}
+
+const POINT: Point = unsafe { transmute([3u32, 4u32]) };
+const BACK: [u32; 2] = unsafe { transmute(Point { x: 7, y: 8 }) };
+const REFS: Refs = unsafe { transmute([&1u8, &2u8]) };
+const REF_SUM: u8 = *REFS.first + *REFS.second;
+
+fn main() {
+    assert_eq!(POINT, Point { x: 3, y: 4 });
+    assert_eq!(POINT.x, 3);
+    assert_eq!(POINT.y, 4);
+    assert_eq!(BACK, [7, 8]);
+    assert_eq!(*REFS.first, 1);
+    assert_eq!(*REFS.second, 2);
+    assert_eq!(REF_SUM, 3);
+}
@@ -0,0 +1,26 @@
+// run-pass
+
+// Transmuting between zero-sized types is a no-op.
+
+use std::marker::PhantomData;
+use std::mem;
+
+#[derive(Debug, PartialEq)]
+struct Unit;
+
+#[derive(Debug, PartialEq)]
+struct Wrapper<T>(PhantomData<T>, ());
+
+const PHANTOM: PhantomData<u16> = unsafe { mem::transmute(PhantomData::<u8>) };
+const UNIT: () = unsafe { mem::transmute(Unit) };
+const FROM_UNIT: Unit = unsafe { mem::transmute(()) };
+const WRAPPER: Wrapper<u32> = unsafe { mem::transmute(Wrapper::<u8>(PhantomData, ())) };
+const ARRAY: [u64; 0] = unsafe { mem::transmute([0u8; 0]) };
+
+fn main() {
+    assert_eq!(PHANTOM, PhantomData::<u16>);
+    assert_eq!(UNIT, ());
+    assert_eq!(FROM_UNIT, Unit);
+    assert_eq!(WRAPPER, Wrapper(PhantomData, ()));
+    assert_eq!(ARRAY, []);
+}
@@ -0,0 +1,31 @@
+// Reading through a wide pointer to a type with a sized prefix and an unsized tail. The
+// metadata describes the tail, which does not start at offset zero.
+
+// run-pass
+
+#![feature(unsized_tuple_coercion)]
+
+struct Tail<T: ?Sized> {
+    head: u32,
+    tail: T,
+}
+
+const STRUCT: &Tail<[u8]> = &Tail { head: 7, tail: [1, 2, 3] };
+const TUPLE: &(u32, [u8]) = &(9, [4, 5]);
+
+const STRUCT_HEAD: u32 = STRUCT.head;
+const STRUCT_LEN: usize = STRUCT.tail.len();
+const STRUCT_LAST: u8 = STRUCT.tail[2];
+const TUPLE_HEAD: u32 = TUPLE.0;
+const TUPLE_LEN: usize = TUPLE.1.len();
+const TUPLE_FIRST: u8 = TUPLE.1[0];
+
+fn main() {
+    assert_eq!(STRUCT_HEAD, 7);
+    assert_eq!(STRUCT_LEN, 3);
+    assert_eq!(STRUCT_LAST, 3);
+    assert_eq!(TUPLE_HEAD, 9);
+    assert_eq!(TUPLE_LEN, 2);
+    assert_eq!(TUPLE_FIRST, 4);
+    assert_eq!(&STRUCT.tail, &[1, 2, 3]);
+}
@@ -0,0 +1,48 @@
+// run-pass
+
+// Slice and trait object pointers keep their metadata when passed around by value, without
+// needing to be spilled to memory first.
+
+#![feature(const_fn)]
+#![feature(const_size_of_val)]
+
+use std::fmt::Debug;
+use std::mem::size_of_val;
+
+struct Holder<'a> {
+    bytes: &'a [u8],
+    obj: &'a dyn Debug,
+}
+
+const fn pass_slice(s: &[u8]) -> &[u8] {
+    s
+}
+
+const fn pass_dyn(d: &dyn Debug) -> &dyn Debug {
+    d
+}
+
+const fn swap_slices<'a>(pair: (&'a [u8], &'a [u8])) -> (&'a [u8], &'a [u8]) {
+    (pair.1, pair.0)
+}
+
+const fn hold<'a>(bytes: &'a [u8], obj: &'a dyn Debug) -> Holder<'a> {
+    Holder { bytes, obj }
+}
+
+const SLICE: &[u8] = pass_slice(&[1, 2, 3, 4, 5]);
+const SWAPPED: (&[u8], &[u8]) = swap_slices((&[1], &[2, 3, 4]));
+const HELD: Holder<'static> = hold(b"hello", &0u64);
+const HELD_LEN: usize = HELD.bytes.len();
+const HELD_SIZE: usize = size_of_val(HELD.obj);
+const DYN_SIZE: usize = size_of_val(pass_dyn(&[0u16; 3]));
+
+fn main() {
+    assert_eq!(SLICE, &[1, 2, 3, 4, 5]);
+    assert_eq!(SWAPPED.0, &[2, 3, 4]);
+    assert_eq!(SWAPPED.1, &[1]);
+    assert_eq!(HELD_LEN, 5);
+    assert_eq!(HELD_SIZE, 8);
+    assert_eq!(format!("{:?}", HELD.obj), "0");
+    assert_eq!(DYN_SIZE, 6);
+}
@@ -25,6 +25,15 @@ const CONST_STRUCT: StructInstantiation = StructInstantiation {
 
 const CONST_STRUCT_NAME: &'static str = type_name_wrapper(&CONST_STRUCT);
 
+// Lifetimes are not part of the type name.
+const fn ref_type_name<'a>() -> &'static str {
+    core::intrinsics::type_name::<&'a u32>()
+}
+
+const REF_NAME: &'static str = ref_type_name();
+const STATIC_REF_NAME: &'static str = core::intrinsics::type_name::<&'static u32>();
+const NESTED_REF_NAME: &'static str = core::intrinsics::type_name::<Option<&'static mut [u8]>>();
+
 fn main() {
     let non_const_struct = StructInstantiation {
         a: 87,
@@ -35,4 +44,8 @@ fn main() {
     let non_const_struct_name = type_name_wrapper(&non_const_struct);
 
     assert_eq!(CONST_STRUCT_NAME, non_const_struct_name);
+
+    assert_eq!(REF_NAME, "&u32");
+    assert_eq!(STATIC_REF_NAME, "&u32");
+    assert_eq!(NESTED_REF_NAME, "core::option::Option<&mut [u8]>");
 }
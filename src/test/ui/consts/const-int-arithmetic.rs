@@ -67,6 +67,15 @@ suite!(
         CHK_ADD_I128: i128::MAX.checked_add(1), None;
         CHK_MUL_I128: i128::MIN.checked_mul(-1), None;
     }
+    checked_shift_u32 -> Option<u32> {
+        CHK_SHL_U32: 1u32.checked_shl(4), Some(16);
+        CHK_SHL_U32_MAX: 1u32.checked_shl(31), Some(1 << 31);
+        CHK_SHL_U32_OOB: 1u32.checked_shl(32), None;
+        CHK_SHL_U32_OOB2: 1u32.checked_shl(40), None;
+
+        CHK_SHR_U32: 0x10u32.checked_shr(4), Some(1);
+        CHK_SHR_U32_OOB: 0x10u32.checked_shr(32), None;
+    }
 
     saturating_and_wrapping -> i8 {
         // `const_saturating_int_methods`
@@ -138,6 +147,7 @@ suite!(
 fn main() {
    checked();
    checked_i128();
+   checked_shift_u32();
    saturating_and_wrapping();
    saturating_and_wrapping_i128();
    overflowing();
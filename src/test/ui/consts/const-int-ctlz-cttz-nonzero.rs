@@ -0,0 +1,37 @@
+// run-pass
+
+// The `_nonzero` variants of `ctlz` and `cttz` must agree with the plain ones on nonzero inputs.
+
+#![feature(core_intrinsics)]
+#![feature(constctlz)]
+#![feature(const_cttz)]
+
+use std::intrinsics::{ctlz, ctlz_nonzero, cttz, cttz_nonzero};
+
+macro_rules! check {
+    ($($name:ident: $ty:ty = $val:expr;)*) => {
+        $(
+            const $name: [$ty; 4] = unsafe {
+                [ctlz($val), ctlz_nonzero($val), cttz($val), cttz_nonzero($val)]
+            };
+        )*
+    }
+}
+
+check! {
+    U8_ONE: u8 = 1u8;
+    U8_MAX: u8 = u8::MAX;
+    I16_NEG: i16 = -2i16;
+    U32_MID: u32 = 0x0001_0000u32;
+    I64_MIN: i64 = i64::MIN;
+    U128_TOP: u128 = 1u128 << 100;
+}
+
+fn main() {
+    assert_eq!(U8_ONE, [7, 7, 0, 0]);
+    assert_eq!(U8_MAX, [0, 0, 0, 0]);
+    assert_eq!(I16_NEG, [0, 0, 1, 1]);
+    assert_eq!(U32_MID, [15, 15, 16, 16]);
+    assert_eq!(I64_MIN, [0, 0, 63, 63]);
+    assert_eq!(U128_TOP, [27, 27, 100, 100]);
+}
@@ -23,6 +23,13 @@ const ZERO_ROTATE_RIGHT: i8 = 0b0111_1001i8.rotate_right(0);
 const MULTIPLE_ROTATE_LEFT: i32 = 0b0010_0001i32.rotate_left(128);
 const MULTIPLE_ROTATE_RIGHT: i32 = 0b0010_0001i32.rotate_right(128);
 
+// Rotation wraps within the operand's own bit width
+const WIDTH_U8: u8 = 0b1000_0001u8.rotate_left(1);
+const WIDTH_U32_LEFT: u32 = 0x1234_5678u32.rotate_left(8);
+const WIDTH_U32_RIGHT: u32 = 0x1234_5678u32.rotate_right(8);
+const WIDTH_U64: u64 = 0x0123_4567_89ab_cdefu64.rotate_left(16);
+const WIDTH_U128: u128 = 1u128.rotate_right(1);
+
 fn main() {
     assert_eq!(LEFT, 0xb301);
     assert_eq!(RIGHT, 0x0100_00b3);
@@ -40,4 +47,10 @@ fn main() {
 
     assert_eq!(MULTIPLE_ROTATE_LEFT, 0b0010_0001);
     assert_eq!(MULTIPLE_ROTATE_RIGHT, 0b0010_0001);
+
+    assert_eq!(WIDTH_U8, 0b0000_0011);
+    assert_eq!(WIDTH_U32_LEFT, 0x3456_7812);
+    assert_eq!(WIDTH_U32_RIGHT, 0x7812_3456);
+    assert_eq!(WIDTH_U64, 0x4567_89ab_cdef_0123);
+    assert_eq!(WIDTH_U128, 1 << 127);
 }
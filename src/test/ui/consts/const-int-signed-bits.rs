@@ -0,0 +1,40 @@
+// The bit-level integer intrinsics operate on the bit pattern of signed values and
+// reinterpret the result as signed again.
+
+// run-pass
+
+const SWAP_NEG_ONE: i32 = (-1i32).swap_bytes();
+const SWAP_NEG_TWO: i16 = (-2i16).swap_bytes();
+const SWAP_TO_NEG: i32 = 0x80i32.swap_bytes();
+
+const REVERSE_MIN: i8 = i8::MIN.reverse_bits();
+const REVERSE_ONE: i8 = 1i8.reverse_bits();
+const REVERSE_NEG_TWO: i64 = (-2i64).reverse_bits();
+
+const ONES_MIN: u32 = i8::MIN.count_ones();
+const ONES_NEG_ONE: u32 = (-1i64).count_ones();
+const ONES_NEG_TWO: u32 = (-2i128).count_ones();
+
+const LEADING_NEG: u32 = (-1i32).leading_zeros();
+const LEADING_ONE: u32 = 1i16.leading_zeros();
+const TRAILING_MIN: u32 = i8::MIN.trailing_zeros();
+const TRAILING_NEG_ONE: u32 = (-1i128).trailing_zeros();
+
+fn main() {
+    assert_eq!(SWAP_NEG_ONE, -1);
+    assert_eq!(SWAP_NEG_TWO, -257);
+    assert_eq!(SWAP_TO_NEG, i32::MIN);
+
+    assert_eq!(REVERSE_MIN, 1);
+    assert_eq!(REVERSE_ONE, i8::MIN);
+    assert_eq!(REVERSE_NEG_TWO, i64::MAX);
+
+    assert_eq!(ONES_MIN, 1);
+    assert_eq!(ONES_NEG_ONE, 64);
+    assert_eq!(ONES_NEG_TWO, 127);
+
+    assert_eq!(LEADING_NEG, 0);
+    assert_eq!(LEADING_ONE, 15);
+    assert_eq!(TRAILING_MIN, 7);
+    assert_eq!(TRAILING_NEG_ONE, 0);
+}
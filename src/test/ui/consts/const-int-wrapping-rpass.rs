@@ -9,6 +9,11 @@ const SUB_B: u32 = 100u32.wrapping_sub(u32::MAX);
 const MUL_A: u8 = 10u8.wrapping_mul(12);
 const MUL_B: u8 = 25u8.wrapping_mul(12);
 
+const U8_MAX_ADD: u8 = u8::MAX.wrapping_add(1);
+const U8_MIN_SUB: u8 = 0u8.wrapping_sub(1);
+const U8_MAX_MUL: u8 = u8::MAX.wrapping_mul(2);
+const I8_MAX_ADD: i8 = i8::MAX.wrapping_add(1);
+
 const SHL_A: u32 = 1u32.wrapping_shl(7);
 const SHL_B: u32 = 1u32.wrapping_shl(128);
 
@@ -32,6 +37,11 @@ fn main() {
     assert_eq!(MUL_A, 120);
     assert_eq!(MUL_B, 44);
 
+    assert_eq!(U8_MAX_ADD, 0);
+    assert_eq!(U8_MIN_SUB, u8::MAX);
+    assert_eq!(U8_MAX_MUL, 254);
+    assert_eq!(I8_MAX_ADD, i8::MIN);
+
     assert_eq!(SHL_A, 128);
     assert_eq!(SHL_B, 1);
 
@@ -6,10 +6,18 @@ struct Trivial(u8, f32);
 
 struct NonTrivial(u8, String);
 
+const fn generic_needs_drop<T>() -> bool {
+    mem::needs_drop::<T>()
+}
+
 const CONST_U8: bool = mem::needs_drop::<u8>();
 const CONST_STRING: bool = mem::needs_drop::<String>();
 const CONST_TRIVIAL: bool = mem::needs_drop::<Trivial>();
 const CONST_NON_TRIVIAL: bool = mem::needs_drop::<NonTrivial>();
+const CONST_COPY_TUPLE: bool = mem::needs_drop::<(u32, char, &String)>();
+const CONST_TUPLE: bool = mem::needs_drop::<(u32, String)>();
+const CONST_GENERIC_U32: bool = generic_needs_drop::<u32>();
+const CONST_GENERIC_STRING: bool = generic_needs_drop::<String>();
 
 static STATIC_U8: bool = mem::needs_drop::<u8>();
 static STATIC_STRING: bool = mem::needs_drop::<String>();
@@ -21,6 +29,10 @@ fn main() {
     assert!(CONST_STRING);
     assert!(!CONST_TRIVIAL);
     assert!(CONST_NON_TRIVIAL);
+    assert!(!CONST_COPY_TUPLE);
+    assert!(CONST_TUPLE);
+    assert!(!CONST_GENERIC_U32);
+    assert!(CONST_GENERIC_STRING);
 
     assert!(!STATIC_U8);
     assert!(STATIC_STRING);
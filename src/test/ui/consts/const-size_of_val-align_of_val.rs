@@ -2,6 +2,7 @@
 
 #![feature(const_size_of_val, const_align_of_val)]
 
+use std::fmt::Debug;
 use std::mem;
 
 struct Foo(u32);
@@ -31,6 +32,24 @@ const ALIGN_OF_BAR: usize = mem::align_of_val(&BAR);
 const ALIGN_OF_UGH: usize = mem::align_of_val(&UGH);
 
 const SIZE_OF_SLICE: usize = mem::size_of_val("foobar".as_bytes());
+const ALIGN_OF_SLICE: usize = mem::align_of_val(&[1u32, 2, 3] as &[u32]);
+
+const fn size_of_unsized<T: ?Sized>(val: &T) -> usize {
+    mem::size_of_val(val)
+}
+
+const fn align_of_unsized<T: ?Sized>(val: &T) -> usize {
+    mem::align_of_val(val)
+}
+
+const DYN_U64: &dyn Debug = &1u64;
+const SIZE_OF_DYN: usize = mem::size_of_val(DYN_U64);
+const ALIGN_OF_DYN: usize = mem::align_of_val(DYN_U64);
+
+const GENERIC_SIZE_OF_STR: usize = size_of_unsized("foobar");
+const GENERIC_SIZE_OF_DYN: usize = size_of_unsized(DYN_U64);
+const GENERIC_ALIGN_OF_SLICE: usize = align_of_unsized(&[1u16, 2, 3] as &[u16]);
+const GENERIC_ALIGN_OF_DYN: usize = align_of_unsized(DYN_U64);
 
 fn main() {
     assert_eq!(SIZE_OF_FOO, mem::size_of::<Foo>());
@@ -42,4 +61,13 @@ fn main() {
     assert_eq!(ALIGN_OF_UGH, mem::align_of::<Ugh>());
 
     assert_eq!(SIZE_OF_SLICE, "foobar".len());
+    assert_eq!(ALIGN_OF_SLICE, mem::align_of::<u32>());
+
+    assert_eq!(SIZE_OF_DYN, mem::size_of::<u64>());
+    assert_eq!(ALIGN_OF_DYN, mem::align_of::<u64>());
+
+    assert_eq!(GENERIC_SIZE_OF_STR, "foobar".len());
+    assert_eq!(GENERIC_SIZE_OF_DYN, mem::size_of::<u64>());
+    assert_eq!(GENERIC_ALIGN_OF_SLICE, mem::align_of::<u16>());
+    assert_eq!(GENERIC_ALIGN_OF_DYN, mem::align_of::<u64>());
 }
@@ -0,0 +1,50 @@
+// run-pass
+
+// Check that the `TypeId`s computed during const evaluation are distinct for
+// types that are commonly compared against each other, and that they agree
+// with the ones computed at runtime.
+
+use std::any::TypeId;
+
+struct Unit;
+struct Wrapper<T>(T);
+enum Empty {}
+
+const IDS: [TypeId; 24] = [
+    TypeId::of::<()>(),
+    TypeId::of::<bool>(),
+    TypeId::of::<char>(),
+    TypeId::of::<u8>(),
+    TypeId::of::<i8>(),
+    TypeId::of::<u16>(),
+    TypeId::of::<i16>(),
+    TypeId::of::<u32>(),
+    TypeId::of::<i32>(),
+    TypeId::of::<u64>(),
+    TypeId::of::<i64>(),
+    TypeId::of::<u128>(),
+    TypeId::of::<i128>(),
+    TypeId::of::<usize>(),
+    TypeId::of::<isize>(),
+    TypeId::of::<f32>(),
+    TypeId::of::<f64>(),
+    TypeId::of::<&'static str>(),
+    TypeId::of::<String>(),
+    TypeId::of::<Unit>(),
+    TypeId::of::<Wrapper<u8>>(),
+    TypeId::of::<Wrapper<i8>>(),
+    TypeId::of::<Empty>(),
+    TypeId::of::<*const u8>(),
+];
+
+fn main() {
+    for i in 0..IDS.len() {
+        for j in 0..IDS.len() {
+            assert_eq!(i == j, IDS[i] == IDS[j], "type ids {} and {} collide", i, j);
+        }
+    }
+
+    assert_eq!(IDS[0], TypeId::of::<()>());
+    assert_eq!(IDS[18], TypeId::of::<String>());
+    assert_eq!(IDS[21], TypeId::of::<Wrapper<i8>>());
+}
@@ -1,7 +1,9 @@
 // run-pass
 #![feature(const_discriminant)]
+#![feature(core_intrinsics)]
 #![allow(dead_code)]
 
+use std::intrinsics::discriminant_value;
 use std::mem::{discriminant, Discriminant};
 
 // `discriminant(const_expr)` may get const-propagated.
@@ -29,6 +31,18 @@ enum SingleVariant {
 
 const TEST_V: Discriminant<SingleVariant> = discriminant(&SingleVariant::V);
 
+#[repr(i32)]
+enum Signed {
+    Neg = -1,
+    Zero = 0,
+    Min = i32::MIN,
+}
+
+const TEST_NEG: Discriminant<Signed> = discriminant(&Signed::Neg);
+const TEST_NEG_VALUE: i32 = unsafe { discriminant_value(&Signed::Neg) };
+const TEST_ZERO_VALUE: i32 = unsafe { discriminant_value(&Signed::Zero) };
+const TEST_MIN_VALUE: i32 = unsafe { discriminant_value(&Signed::Min) };
+
 fn main() {
     assert_eq!(TEST_A, TEST_A_OTHER);
     assert_eq!(TEST_A, discriminant(identity(&Test::A(17))));
@@ -37,4 +51,10 @@ fn main() {
     assert_ne!(TEST_B, discriminant(identity(&Test::C { a: 42, b: 7 })));
 
     assert_eq!(TEST_V, discriminant(identity(&SingleVariant::V)));
+
+    assert_eq!(TEST_NEG, discriminant(identity(&Signed::Neg)));
+    assert_ne!(TEST_NEG, discriminant(identity(&Signed::Zero)));
+    assert_eq!(TEST_NEG_VALUE, -1);
+    assert_eq!(TEST_ZERO_VALUE, 0);
+    assert_eq!(TEST_MIN_VALUE, i32::MIN);
 }
@@ -0,0 +1,43 @@
+// Writing every element of an array in a loop, then reading it back. This exercises the
+// same field and element layouts many times over within a single evaluation.
+
+// run-pass
+
+#[derive(Copy, Clone)]
+struct Cell {
+    tag: u8,
+    value: u64,
+}
+
+const fn fill() -> [Cell; 64] {
+    let mut cells = [Cell { tag: 0, value: 0 }; 64];
+    let mut i = 0;
+    while i < cells.len() {
+        cells[i].tag = (i % 3) as u8;
+        cells[i].value = (i * i) as u64;
+        i += 1;
+    }
+    cells
+}
+
+const fn checksum(cells: &[Cell; 64]) -> u64 {
+    let mut sum = 0;
+    let mut i = 0;
+    while i < cells.len() {
+        if cells[i].tag == 1 {
+            sum += cells[i].value;
+        }
+        i += 1;
+    }
+    sum
+}
+
+const CELLS: [Cell; 64] = fill();
+const CHECKSUM: u64 = checksum(&CELLS);
+
+fn main() {
+    let expected: u64 = (0..64u64).filter(|i| i % 3 == 1).map(|i| i * i).sum();
+    assert_eq!(CHECKSUM, expected);
+    assert_eq!(CELLS[63].tag, 0);
+    assert_eq!(CELLS[63].value, 63 * 63);
+}
@@ -14,6 +14,16 @@ const ABS_DIFF: u32 = if X < Y {
     X - Y
 };
 
+const SOME_OR_ZERO: u32 = match Some(3) {
+    Some(n) => n,
+    None => 0,
+};
+
+const NONE_OR_ZERO: u32 = match None::<u32> {
+    Some(n) => n,
+    None => 0,
+};
+
 const fn abs_diff(a: u32, b: u32) -> u32 {
     match (a, b) {
         (big, little) if big > little => big - little,
@@ -74,6 +84,9 @@ fn main() {
     const_assert!(abs_diff(4, 5) == abs_diff(5, 4));
     const_assert!(ABS_DIFF == abs_diff(5, 4));
 
+    const_assert!(SOME_OR_ZERO == 3);
+    const_assert!(NONE_OR_ZERO == 0);
+
     const_assert!(gcd(48, 18) == 6);
     const_assert!(gcd(18, 48) == 6);
 
@@ -0,0 +1,53 @@
+// Test that match guards are evaluated correctly in a const context, including guards that
+// borrow the scrutinee.
+
+// run-pass
+
+#[derive(Debug, PartialEq, Eq)]
+enum Size {
+    Small,
+    Big,
+}
+
+const fn classify(x: u32) -> Size {
+    match x {
+        n if n > 5 => Size::Big,
+        _ => Size::Small,
+    }
+}
+
+const fn first_big(pair: (u32, u32)) -> Option<u32> {
+    match &pair {
+        &(a, _) if a > 5 => Some(a),
+        (_, b) if *b > 5 => Some(*b),
+        _ => None,
+    }
+}
+
+const fn guard_on_option(x: Option<u32>) -> u32 {
+    match x {
+        Some(n) if n % 2 == 0 => n / 2,
+        Some(n) => n,
+        None => 0,
+    }
+}
+
+const BIG: Size = classify(10);
+const SMALL: Size = classify(5);
+const FIRST: Option<u32> = first_big((7, 9));
+const SECOND: Option<u32> = first_big((1, 9));
+const NEITHER: Option<u32> = first_big((1, 2));
+const EVEN: u32 = guard_on_option(Some(8));
+const ODD: u32 = guard_on_option(Some(7));
+const NONE: u32 = guard_on_option(None);
+
+fn main() {
+    assert_eq!(BIG, Size::Big);
+    assert_eq!(SMALL, Size::Small);
+    assert_eq!(FIRST, Some(7));
+    assert_eq!(SECOND, Some(9));
+    assert_eq!(NEITHER, None);
+    assert_eq!(EVEN, 4);
+    assert_eq!(ODD, 7);
+    assert_eq!(NONE, 0);
+}
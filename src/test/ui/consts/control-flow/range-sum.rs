@@ -0,0 +1,68 @@
+// Summing over a range during const evaluation. `Iterator::sum` itself is not a
+// `const fn`, so this mirrors what `Range::next` and `Iterator::fold` do: compare,
+// increment, and hand back elements wrapped in an `Option`.
+
+// run-pass
+
+#![feature(const_mut_refs)]
+#![feature(const_fn)]
+
+use std::num::NonZeroU32;
+
+struct Range {
+    start: u32,
+    end: u32,
+}
+
+impl Range {
+    const fn new(start: u32, end: u32) -> Self {
+        Range { start, end }
+    }
+
+    const fn next(&mut self) -> Option<u32> {
+        if self.start < self.end {
+            let n = self.start;
+            self.start += 1;
+            Some(n)
+        } else {
+            None
+        }
+    }
+
+    // Uses the niche of `NonZeroU32` for the `None` representation.
+    const fn next_nonzero(&mut self) -> Option<NonZeroU32> {
+        match self.next() {
+            Some(n) => NonZeroU32::new(n + 1),
+            None => None,
+        }
+    }
+}
+
+const fn sum(start: u32, end: u32) -> u32 {
+    let mut range = Range::new(start, end);
+    let mut acc = 0;
+    while let Some(n) = range.next() {
+        acc += n;
+    }
+    acc
+}
+
+const fn sum_nonzero(start: u32, end: u32) -> u32 {
+    let mut range = Range::new(start, end);
+    let mut acc = 0;
+    while let Some(n) = range.next_nonzero() {
+        acc += n.get();
+    }
+    acc
+}
+
+const SUM: u32 = sum(0, 10);
+const SUM_EMPTY: u32 = sum(10, 0);
+const SUM_NONZERO: u32 = sum_nonzero(0, 10);
+
+fn main() {
+    assert_eq!(SUM, 45);
+    assert_eq!(SUM, (0..10).sum::<u32>());
+    assert_eq!(SUM_EMPTY, 0);
+    assert_eq!(SUM_NONZERO, 55);
+}
@@ -0,0 +1,57 @@
+// The `?` operator itself is not allowed in a `const fn` (see `try.rs`), but the code it
+// desugars to is. Check that both the success-propagation and the early-return-on-error
+// paths evaluate correctly for `Result` and `Option`.
+
+// run-pass
+
+#[derive(Debug, PartialEq, Eq)]
+enum Error {
+    TooBig(u32),
+    Zero,
+}
+
+const fn check(x: u32) -> Result<u32, Error> {
+    if x == 0 {
+        Err(Error::Zero)
+    } else if x > 100 {
+        Err(Error::TooBig(x))
+    } else {
+        Ok(x)
+    }
+}
+
+// Equivalent to `Ok(check(a)? + check(b)?)`.
+const fn add(a: u32, b: u32) -> Result<u32, Error> {
+    let a = match check(a) {
+        Ok(a) => a,
+        Err(e) => return Err(e),
+    };
+    let b = match check(b) {
+        Ok(b) => b,
+        Err(e) => return Err(e),
+    };
+    Ok(a + b)
+}
+
+// Equivalent to `Some(a? * 2)`.
+const fn double(a: Option<u32>) -> Option<u32> {
+    let a = match a {
+        Some(a) => a,
+        None => return None,
+    };
+    Some(a * 2)
+}
+
+const OK: Result<u32, Error> = add(20, 22);
+const ERR_FIRST: Result<u32, Error> = add(0, 22);
+const ERR_SECOND: Result<u32, Error> = add(20, 220);
+const SOME: Option<u32> = double(Some(21));
+const NONE: Option<u32> = double(None);
+
+fn main() {
+    assert_eq!(OK, Ok(42));
+    assert_eq!(ERR_FIRST, Err(Error::Zero));
+    assert_eq!(ERR_SECOND, Err(Error::TooBig(220)));
+    assert_eq!(SOME, Some(42));
+    assert_eq!(NONE, None);
+}
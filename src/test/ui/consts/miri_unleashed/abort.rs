@@ -0,0 +1,18 @@
+// compile-flags: -Zunleash-the-miri-inside-of-you
+#![feature(const_panic)]
+#![feature(const_unreachable_unchecked)]
+#![feature(core_intrinsics)]
+#![allow(const_err)]
+
+// Panics, aborts and unreachable code are reported with distinct messages.
+
+static PANIC: () = panic!("boom");
+//~^ ERROR could not evaluate static initializer
+
+static ABORT: () = unsafe { std::intrinsics::abort() };
+//~^ ERROR could not evaluate static initializer
+
+static UNREACHABLE: () = unsafe { std::intrinsics::unreachable() };
+//~^ ERROR could not evaluate static initializer
+
+fn main() {}
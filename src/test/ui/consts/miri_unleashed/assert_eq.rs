@@ -0,0 +1,20 @@
+// compile-flags: -Zunleash-the-miri-inside-of-you
+// The skipped checks point into the expansion of `assert_eq!`, so don't compare the output.
+// dont-check-compiler-stderr
+#![allow(const_err)]
+
+// Integer `assert_eq!` compares the values directly and only calls into formatting when they
+// differ. A passing assertion is evaluated without reaching those calls, while a failing one
+// stops the evaluation at the first of them.
+
+static PASS: u32 = {
+    assert_eq!(1 + 1, 2);
+    7
+};
+// This indexes out of bounds if evaluation did not continue after the assertion.
+static CHECK: () = [()][(PASS != 7) as usize];
+
+static FAIL: () = assert_eq!(1, 2);
+//~^ ERROR could not evaluate static initializer
+
+fn main() {}
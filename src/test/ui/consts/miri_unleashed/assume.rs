@@ -0,0 +1,13 @@
+// compile-flags: -Zunleash-the-miri-inside-of-you
+#![feature(core_intrinsics)]
+#![allow(const_err)]
+
+// A violated `assume` is undefined behavior, reported at the call site.
+
+use std::intrinsics::assume;
+
+static HOLDS: () = unsafe { assume(true) };
+static VIOLATED: () = unsafe { assume(false) };
+//~^ ERROR could not evaluate static initializer
+
+fn main() {}
@@ -0,0 +1,33 @@
+// run-pass
+// compile-flags: -Zunleash-the-miri-inside-of-you
+#![feature(intrinsics, const_mut_refs)]
+
+// CTFE is single-threaded, so atomic loads and stores of any ordering are plain accesses.
+
+extern "rust-intrinsic" {
+    fn atomic_load<T>(src: *const T) -> T;
+    fn atomic_load_acq<T>(src: *const T) -> T;
+    fn atomic_load_relaxed<T>(src: *const T) -> T;
+    fn atomic_store<T>(dst: *mut T, val: T);
+    fn atomic_store_rel<T>(dst: *mut T, val: T);
+    fn atomic_store_relaxed<T>(dst: *mut T, val: T);
+}
+
+const fn load_and_store() -> [usize; 3] {
+    let mut x = 1usize;
+    unsafe {
+        let ptr = &mut x as *mut usize;
+        let a = atomic_load(ptr);
+        atomic_store_rel(ptr, 2);
+        let b = atomic_load_relaxed(ptr);
+        atomic_store(ptr, 4);
+        atomic_store_relaxed(ptr, a + b + 4);
+        [a, b, atomic_load_acq(ptr)]
+    }
+}
+
+const VALUES: [usize; 3] = load_and_store();
+
+fn main() {
+    assert_eq!(VALUES, [1, 2, 7]);
+}
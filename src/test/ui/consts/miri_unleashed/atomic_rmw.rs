@@ -0,0 +1,48 @@
+// run-pass
+// compile-flags: -Zunleash-the-miri-inside-of-you
+#![feature(intrinsics, const_mut_refs)]
+
+// CTFE is single-threaded, so atomic read-modify-write operations and compare-exchanges of any
+// ordering just read, update and write back the value.
+
+extern "rust-intrinsic" {
+    fn atomic_xadd<T>(dst: *mut T, src: T) -> T;
+    fn atomic_xsub_acq<T>(dst: *mut T, src: T) -> T;
+    fn atomic_xchg_rel<T>(dst: *mut T, src: T) -> T;
+    fn atomic_and_acqrel<T>(dst: *mut T, src: T) -> T;
+    fn atomic_or_relaxed<T>(dst: *mut T, src: T) -> T;
+    fn atomic_xor<T>(dst: *mut T, src: T) -> T;
+    fn atomic_nand<T>(dst: *mut T, src: T) -> T;
+    fn atomic_cxchg<T>(dst: *mut T, old: T, src: T) -> (T, bool);
+    fn atomic_cxchgweak_failrelaxed<T>(dst: *mut T, old: T, src: T) -> (T, bool);
+}
+
+const fn read_modify_write() -> ([usize; 7], (usize, bool), (usize, bool), usize) {
+    let mut x = 5usize;
+    unsafe {
+        let ptr = &mut x as *mut usize;
+        let old = [
+            atomic_xadd(ptr, 2),
+            atomic_xsub_acq(ptr, 1),
+            atomic_xchg_rel(ptr, 0b1100),
+            atomic_and_acqrel(ptr, 0b1010),
+            atomic_or_relaxed(ptr, 0b0011),
+            atomic_xor(ptr, 0b0110),
+            atomic_nand(ptr, !0b0100),
+        ];
+        let exchanged = atomic_cxchg(ptr, !9, 3);
+        // Weak compare-exchanges never fail spuriously here.
+        let not_exchanged = atomic_cxchgweak_failrelaxed(ptr, 4, 5);
+        (old, exchanged, not_exchanged, x)
+    }
+}
+
+const RESULT: ([usize; 7], (usize, bool), (usize, bool), usize) = read_modify_write();
+
+fn main() {
+    let (old, exchanged, not_exchanged, last) = RESULT;
+    assert_eq!(old, [5, 7, 6, 12, 8, 11, 13]);
+    assert_eq!(exchanged, (!9, true));
+    assert_eq!(not_exchanged, (3, false));
+    assert_eq!(last, 3);
+}
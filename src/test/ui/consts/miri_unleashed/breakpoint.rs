@@ -0,0 +1,10 @@
+// compile-flags: -Zunleash-the-miri-inside-of-you
+#![feature(core_intrinsics)]
+#![allow(const_err)]
+
+// There is no debugger to hand control to, so hitting a breakpoint is an error.
+
+static BREAKPOINT: () = unsafe { std::intrinsics::breakpoint() };
+//~^ ERROR could not evaluate static initializer
+
+fn main() {}
@@ -0,0 +1,21 @@
+// run-pass
+// compile-flags: -Zunleash-the-miri-inside-of-you
+#![feature(const_mut_refs)]
+
+// A closure capturing by reference stores pointers to the captured variables in its environment,
+// and calling it goes through them.
+
+const fn count_twice() -> (u32, u32) {
+    let mut c = 0;
+    let mut inc = || c += 1;
+    inc();
+    inc();
+    let read = || c * 10;
+    (c, read())
+}
+
+const COUNT: (u32, u32) = count_twice();
+
+fn main() {
+    assert_eq!(COUNT, (2, 20));
+}
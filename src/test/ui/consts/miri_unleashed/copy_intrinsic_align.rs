@@ -0,0 +1,42 @@
+// run-pass
+// compile-flags: -Zunleash-the-miri-inside-of-you
+#![feature(intrinsics, const_mut_refs, const_ptr_offset)]
+
+// Whether the `copy` family of intrinsics requires aligned pointers is up to the machine.
+// CTFE does not enforce alignment, so misaligned copies work.
+
+extern "rust-intrinsic" {
+    fn copy_nonoverlapping<T>(src: *const T, dst: *mut T, count: usize);
+}
+
+const fn copy_at(src_offset: usize, dst_offset: usize, count: usize) -> [u32; 4] {
+    let src = [1u32, 2, 3, 4];
+    let mut dst = [0u32; 4];
+    unsafe {
+        let src = (&src as *const _ as *const u8).add(src_offset) as *const u32;
+        let dst = (&mut dst as *mut _ as *mut u8).add(dst_offset) as *mut u32;
+        copy_nonoverlapping(src, dst, count);
+    }
+    dst
+}
+
+static ALIGNED: [u32; 4] = copy_at(0, 0, 3);
+static MISALIGNED_SRC: [u32; 4] = copy_at(1, 0, 2);
+static MISALIGNED_DST: [u32; 4] = copy_at(0, 2, 2);
+
+fn bytes(values: [u32; 4]) -> [u8; 16] {
+    unsafe { std::mem::transmute(values) }
+}
+
+fn main() {
+    assert_eq!(ALIGNED, [1, 2, 3, 0]);
+
+    let src = bytes([1, 2, 3, 4]);
+    let mut expected = [0u8; 16];
+    expected[..8].copy_from_slice(&src[1..9]);
+    assert_eq!(bytes(MISALIGNED_SRC), expected);
+
+    let mut expected = [0u8; 16];
+    expected[2..10].copy_from_slice(&src[..8]);
+    assert_eq!(bytes(MISALIGNED_DST), expected);
+}
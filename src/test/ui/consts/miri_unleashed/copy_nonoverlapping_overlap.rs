@@ -0,0 +1,28 @@
+// compile-flags: -Zunleash-the-miri-inside-of-you
+#![feature(intrinsics, const_mut_refs, const_ptr_offset)]
+#![allow(const_err)]
+
+// `copy_nonoverlapping` must not be called on overlapping ranges, no matter whether the copied
+// bytes are initialized.
+
+use std::mem::MaybeUninit;
+
+extern "rust-intrinsic" {
+    fn copy_nonoverlapping<T>(src: *const T, dst: *mut T, count: usize);
+}
+
+const fn copy_within(init: bool, src: usize, dst: usize, count: usize) {
+    let mut buf = if init { MaybeUninit::new([1u32, 2, 3, 4]) } else { MaybeUninit::uninit() };
+    unsafe {
+        let base = &mut buf as *mut _ as *mut u32;
+        copy_nonoverlapping(base.add(src), base.add(dst), count);
+        //~^ ERROR could not evaluate static initializer
+        //~| ERROR could not evaluate static initializer
+    }
+}
+
+static DISJOINT: () = copy_within(true, 0, 2, 2);
+static OVERLAPPING: () = copy_within(true, 0, 1, 2);
+static OVERLAPPING_UNINIT: () = copy_within(false, 0, 1, 2);
+
+fn main() {}
@@ -0,0 +1,49 @@
+// compile-flags: -Zunleash-the-miri-inside-of-you
+#![feature(const_trait_impl)]
+#![allow(incomplete_features, const_err)]
+
+// `Default::default` runs the impl for the concrete type. A `const` impl is evaluated like any
+// other const fn, including when it builds an aggregate from the defaults of its fields, but
+// derived impls are not `const` and stop the evaluation.
+
+struct Inner {
+    flag: bool,
+    ch: char,
+}
+
+impl const Default for Inner {
+    fn default() -> Self {
+        Inner { flag: true, ch: 'x' }
+    }
+}
+
+struct Config {
+    count: u32,
+    offset: i64,
+    inner: Inner,
+    tag: Option<u8>,
+}
+
+impl const Default for Config {
+    fn default() -> Self {
+        Config { count: 3, offset: -1, inner: Default::default(), tag: None }
+    }
+}
+
+static CONFIG: Config = Default::default();
+// These index out of bounds if the defaults are wrong.
+static CHECK_COUNT: () = [()][(CONFIG.count != 3) as usize];
+static CHECK_OFFSET: () = [()][(CONFIG.offset != -1) as usize];
+static CHECK_INNER: () = [()][(!CONFIG.inner.flag | (CONFIG.inner.ch != 'x')) as usize];
+static CHECK_TAG: () = [()][matches!(CONFIG.tag, Some(_)) as usize];
+
+#[derive(Default)]
+struct Derived {
+    count: u32,
+    flag: bool,
+}
+
+static DERIVED: Derived = Default::default();
+//~^ ERROR could not evaluate static initializer
+
+fn main() {}
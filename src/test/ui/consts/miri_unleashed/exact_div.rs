@@ -0,0 +1,22 @@
+// compile-flags: -Zunleash-the-miri-inside-of-you
+#![feature(core_intrinsics)]
+#![allow(const_err)]
+
+use std::intrinsics::exact_div;
+
+// `exact_div` is UB if the division has a remainder, the divisor is zero, or it overflows.
+
+static EXACT: u32 = unsafe { exact_div(10_u32, 2) };
+static EXACT_NEG: i32 = unsafe { exact_div(-12_i32, 4) };
+// These index out of bounds if the results are wrong.
+static CHECK_EXACT: () = [()][(EXACT != 5) as usize];
+static CHECK_EXACT_NEG: () = [()][(EXACT_NEG != -3) as usize];
+
+static REMAINDER: u32 = unsafe { exact_div(10_u32, 3) };
+//~^ ERROR could not evaluate static initializer
+static DIV_BY_ZERO: u32 = unsafe { exact_div(10_u32, 0) };
+//~^ ERROR could not evaluate static initializer
+static OVERFLOW: i32 = unsafe { exact_div(i32::MIN, -1) };
+//~^ ERROR could not evaluate static initializer
+
+fn main() {}
@@ -0,0 +1,19 @@
+// run-pass
+// compile-flags: -Zunleash-the-miri-inside-of-you
+#![feature(core_intrinsics)]
+
+use std::intrinsics::{copysignf32, copysignf64};
+
+const NEG_F32: f32 = unsafe { copysignf32(3.0, -1.0) };
+const POS_F32: f32 = unsafe { copysignf32(-3.0, 0.5) };
+const NEG_ZERO_SIGN: f64 = unsafe { copysignf64(2.5, -0.0) };
+const KEEP_SIGN: f64 = unsafe { copysignf64(-2.5, -7.0) };
+const NEG_INF: f64 = unsafe { copysignf64(f64::INFINITY, -1.0) };
+
+fn main() {
+    assert_eq!(NEG_F32, -3.0);
+    assert_eq!(POS_F32, 3.0);
+    assert_eq!(NEG_ZERO_SIGN, -2.5);
+    assert_eq!(KEEP_SIGN, -2.5);
+    assert_eq!(NEG_INF, f64::NEG_INFINITY);
+}
@@ -0,0 +1,20 @@
+// run-pass
+// compile-flags: -Zunleash-the-miri-inside-of-you
+#![feature(core_intrinsics)]
+
+// `fma` rounds only once. With `e` being the machine epsilon, `(1 + e) * (1 - e) - 1` is
+// `-e * e` when fused, while rounding the product first would yield `0`.
+
+use std::intrinsics::{fmaf32, fmaf64};
+
+const FUSED: (f32, f64) = unsafe {
+    (
+        fmaf32(1.0 + f32::EPSILON, 1.0 - f32::EPSILON, -1.0),
+        fmaf64(1.0 + f64::EPSILON, 1.0 - f64::EPSILON, -1.0),
+    )
+};
+
+fn main() {
+    assert_eq!(FUSED.0, -f32::EPSILON * f32::EPSILON);
+    assert_eq!(FUSED.1, -f64::EPSILON * f64::EPSILON);
+}
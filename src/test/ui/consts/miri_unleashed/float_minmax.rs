@@ -0,0 +1,32 @@
+// run-pass
+// compile-flags: -Zunleash-the-miri-inside-of-you
+#![feature(core_intrinsics)]
+
+use std::intrinsics::{maxnumf32, maxnumf64, minnumf32, minnumf64};
+
+const MIN_F32: f32 = unsafe { minnumf32(1.5, -2.0) };
+const MAX_F32: f32 = unsafe { maxnumf32(1.5, -2.0) };
+const MIN_F64: f64 = unsafe { minnumf64(-0.5, 3.0) };
+const MAX_F64: f64 = unsafe { maxnumf64(-0.5, 3.0) };
+
+// If one operand is NaN, the other one is returned.
+const MIN_NAN_LEFT: f32 = unsafe { minnumf32(f32::NAN, 4.0) };
+const MAX_NAN_RIGHT: f64 = unsafe { maxnumf64(4.0, f64::NAN) };
+const MIN_BOTH_NAN: f64 = unsafe { minnumf64(f64::NAN, f64::NAN) };
+
+const MIN_INF: f32 = unsafe { minnumf32(f32::NEG_INFINITY, f32::MIN) };
+const MAX_INF: f64 = unsafe { maxnumf64(f64::INFINITY, f64::MAX) };
+
+fn main() {
+    assert_eq!(MIN_F32, -2.0);
+    assert_eq!(MAX_F32, 1.5);
+    assert_eq!(MIN_F64, -0.5);
+    assert_eq!(MAX_F64, 3.0);
+
+    assert_eq!(MIN_NAN_LEFT, 4.0);
+    assert_eq!(MAX_NAN_RIGHT, 4.0);
+    assert!(MIN_BOTH_NAN.is_nan());
+
+    assert_eq!(MIN_INF, f32::NEG_INFINITY);
+    assert_eq!(MAX_INF, f64::INFINITY);
+}
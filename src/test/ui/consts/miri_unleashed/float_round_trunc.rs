@@ -0,0 +1,30 @@
+// run-pass
+// compile-flags: -Zunleash-the-miri-inside-of-you
+#![feature(core_intrinsics)]
+
+// `round` rounds half-way cases away from zero and `trunc` rounds towards zero. Both keep the
+// sign of a zero result.
+
+use std::intrinsics::{roundf32, roundf64, truncf32, truncf64};
+
+const fn round_and_trunc(x: f64) -> [f64; 4] {
+    unsafe { [roundf64(x), truncf64(x), roundf32(x as f32) as f64, truncf32(x as f32) as f64] }
+}
+
+const CASES: [[f64; 4]; 4] = [
+    round_and_trunc(2.5),
+    round_and_trunc(-2.5),
+    round_and_trunc(-0.5),
+    round_and_trunc(-0.0),
+];
+
+fn main() {
+    let expected: [(f64, f64); 4] = [(3.0, 2.0), (-3.0, -2.0), (-1.0, -0.0), (-0.0, -0.0)];
+    for (got, &(round, trunc)) in CASES.iter().zip(&expected) {
+        // Compare bit patterns so that the sign of zero is checked as well.
+        assert_eq!(got[0].to_bits(), round.to_bits());
+        assert_eq!(got[1].to_bits(), trunc.to_bits());
+        assert_eq!(got[2].to_bits(), round.to_bits());
+        assert_eq!(got[3].to_bits(), trunc.to_bits());
+    }
+}
@@ -20,3 +20,18 @@ static TEST_BAD2: () = {
     //~^ ERROR could not evaluate static initializer
     //~| NOTE inline assembly is not supported
 };
+
+// Make sure we also catch inline assembly with operands, and inline assembly that never returns.
+static TEST_BAD3: u64 = {
+    let x: u64;
+    unsafe { asm!("mov {}, 1", out(reg) x); }
+    //~^ ERROR could not evaluate static initializer
+    //~| NOTE inline assembly is not supported
+    x
+};
+
+static TEST_BAD4: () = {
+    unsafe { asm!("ud2", options(noreturn)); }
+    //~^ ERROR could not evaluate static initializer
+    //~| NOTE inline assembly is not supported
+};
@@ -0,0 +1,28 @@
+// compile-flags: -Zunleash-the-miri-inside-of-you
+#![allow(const_err)]
+
+// `Option::map` is not a `const fn`, so evaluating it stops at the call. A const fn with the same
+// body can be evaluated, which reads the `Option`, calls the closure through `FnOnce` and builds
+// the `Some` again.
+
+// The `Copy` bound keeps the closure from needing a destructor in the `None` arm.
+const fn map<F: FnOnce(u32) -> u32 + Copy>(opt: Option<u32>, f: F) -> Option<u32> {
+    match opt {
+        Some(x) => Some(f(x)),
+        None => None,
+    }
+}
+
+const fn add_to_options(offset: u32) -> (Option<u32>, Option<u32>) {
+    let add = move |x| x + offset;
+    (map(Some(3), add), map(None, add))
+}
+
+static MAPPED: (Option<u32>, Option<u32>) = add_to_options(1);
+// This indexes out of bounds if the results are wrong.
+static CHECK: () = [()][!matches!(MAPPED, (Some(4), None)) as usize];
+
+static REAL_MAP: Option<u32> = Some(3).map(|x| x + 1);
+//~^ ERROR could not evaluate static initializer
+
+fn main() {}
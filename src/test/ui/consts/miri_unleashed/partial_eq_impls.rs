@@ -0,0 +1,47 @@
+// compile-flags: -Zunleash-the-miri-inside-of-you
+#![feature(const_trait_impl)]
+#![allow(incomplete_features, const_err)]
+
+// `==` calls the `PartialEq::eq` of the concrete type. A `const` impl comparing fields pairwise,
+// short-circuiting on the first unequal one, is evaluated like any other const fn, but derived
+// impls are not `const` and stop the evaluation.
+
+struct Pos {
+    x: i32,
+    y: i32,
+}
+
+impl const PartialEq for Pos {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+}
+
+struct Entry {
+    id: u32,
+    pos: Pos,
+    flag: bool,
+}
+
+impl const PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.pos == other.pos && self.flag == other.flag
+    }
+}
+
+const A: Entry = Entry { id: 1, pos: Pos { x: 2, y: -3 }, flag: true };
+
+static EQUAL: bool = A == Entry { id: 1, pos: Pos { x: 2, y: -3 }, flag: true };
+static OTHER_ID: bool = A == Entry { id: 4, pos: Pos { x: 2, y: -3 }, flag: true };
+static OTHER_POS: bool = A == Entry { id: 1, pos: Pos { x: 2, y: 3 }, flag: true };
+static OTHER_FLAG: bool = A == Entry { id: 1, pos: Pos { x: 2, y: -3 }, flag: false };
+// This indexes out of bounds if a comparison is wrong.
+static CHECK: () = [()][(!EQUAL | OTHER_ID | OTHER_POS | OTHER_FLAG) as usize];
+
+#[derive(PartialEq)]
+struct Derived(u8, bool);
+
+static DERIVED: bool = Derived(1, true) == Derived(1, true);
+//~^ ERROR could not evaluate static initializer
+
+fn main() {}
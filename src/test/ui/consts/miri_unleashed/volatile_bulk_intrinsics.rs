@@ -0,0 +1,56 @@
+// run-pass
+// compile-flags: -Zunleash-the-miri-inside-of-you
+#![feature(intrinsics, core_intrinsics, const_mut_refs, const_ptr_offset)]
+
+// The bulk volatile intrinsics and `write_bytes` work during CTFE. The interpreter does not
+// model volatility, so they behave like `copy`, `copy_nonoverlapping` and `write_bytes`.
+
+use std::intrinsics::{
+    volatile_copy_memory, volatile_copy_nonoverlapping_memory, volatile_set_memory,
+};
+
+extern "rust-intrinsic" {
+    fn write_bytes<T>(dst: *mut T, val: u8, count: usize);
+}
+
+const fn bulk() -> [[u16; 4]; 4] {
+    let mut moved = [1u16, 2, 3, 4];
+    let mut copied = [0u16; 4];
+    let mut set = [0u16; 4];
+    let mut written = [0u16; 4];
+    unsafe {
+        let moved_ptr = &mut moved as *mut _ as *mut u16;
+        // Overlapping ranges are fine, as with `copy`. Note that the destination comes first.
+        volatile_copy_memory(moved_ptr.add(1), moved_ptr, 3);
+        let copied_ptr = &mut copied as *mut _ as *mut u16;
+        volatile_copy_nonoverlapping_memory(copied_ptr, &moved as *const _ as *const u16, 2);
+        let set_ptr = &mut set as *mut _ as *mut u16;
+        volatile_set_memory(set_ptr.add(1), 0xab, 2);
+        let written_ptr = &mut written as *mut _ as *mut u16;
+        write_bytes(written_ptr.add(2), 0xcd, 2);
+    }
+    [moved, copied, set, written]
+}
+
+const BULK: [[u16; 4]; 4] = bulk();
+
+const fn misaligned_write_bytes() -> [u16; 2] {
+    let mut misaligned = [0u16; 2];
+    unsafe {
+        // CTFE does not enforce alignment, so a misaligned destination is fine.
+        let misaligned_ptr = (&mut misaligned as *mut _ as *mut u8).add(1) as *mut u16;
+        write_bytes(misaligned_ptr, 0xef, 1);
+    }
+    misaligned
+}
+
+const MISALIGNED: [u16; 2] = misaligned_write_bytes();
+
+fn main() {
+    assert_eq!(BULK[0], [1, 1, 2, 3]);
+    assert_eq!(BULK[1], [1, 1, 0, 0]);
+    assert_eq!(BULK[2], [0, 0xabab, 0xabab, 0]);
+    assert_eq!(BULK[3], [0, 0, 0xcdcd, 0xcdcd]);
+    assert_eq!(MISALIGNED[0].to_ne_bytes(), [0, 0xef]);
+    assert_eq!(MISALIGNED[1].to_ne_bytes(), [0xef, 0]);
+}
@@ -0,0 +1,22 @@
+// run-pass
+// compile-flags: -Zunleash-the-miri-inside-of-you
+#![feature(core_intrinsics, const_ptr_offset)]
+
+// Whether volatile accesses must be aligned is up to the machine. CTFE does not enforce
+// alignment, so a misaligned volatile load works.
+
+use std::intrinsics::volatile_load;
+
+const fn load_at(offset: usize) -> u32 {
+    // Every byte is the same, so the result does not depend on endianness.
+    let data = [0x0101_0101u32, 0x0101_0101];
+    unsafe { volatile_load((&data as *const _ as *const u8).add(offset) as *const u32) }
+}
+
+static ALIGNED: u32 = load_at(4);
+static MISALIGNED: u32 = load_at(1);
+
+fn main() {
+    assert_eq!(ALIGNED, 0x0101_0101);
+    assert_eq!(MISALIGNED, 0x0101_0101);
+}
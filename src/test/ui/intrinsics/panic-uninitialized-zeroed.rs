@@ -1,5 +1,7 @@
 // run-pass
 // ignore-wasm32-bare compiled with panic=abort by default
+// revisions: default strict
+// [strict]compile-flags: -Zstrict-init-checks
 
 // This test checks panic emitted from `mem::{uninitialized,zeroed}`.
 
@@ -105,6 +107,23 @@ fn main() {
             "attempted to zero-initialize type `*const dyn std::marker::Send`, which is invalid"
         );
 
+        // Arrays are invalid if their element type is. FIXME(#66151): this is only checked with
+        // `-Zstrict-init-checks` for now.
+        if cfg!(strict) {
+            test_panic_msg(
+                || mem::uninitialized::<[&u8; 4]>(),
+                "attempted to leave type `[&u8; 4]` uninitialized, which is invalid"
+            );
+            test_panic_msg(
+                || mem::zeroed::<[&u8; 4]>(),
+                "attempted to zero-initialize type `[&u8; 4]`, which is invalid"
+            );
+            test_panic_msg(
+                || mem::uninitialized::<[bool; 2]>(),
+                "attempted to leave type `[bool; 2]` uninitialized, which is invalid"
+            );
+        }
+
         /* FIXME(#66151) we conservatively do not error here yet.
         test_panic_msg(
             || mem::uninitialized::<LR_NonZero>(),
@@ -160,11 +179,14 @@ fn main() {
         let _val = mem::zeroed::<Option<&'static i32>>();
         let _val = mem::zeroed::<MaybeUninit<NonNull<u32>>>();
         let _val = mem::uninitialized::<MaybeUninit<bool>>();
+        let _val = mem::zeroed::<[bool; 2]>();
+        let _val = mem::uninitialized::<[&u8; 0]>();
 
         // These are UB because they have not been officially blessed, but we await the resolution
         // of <https://github.com/rust-lang/unsafe-code-guidelines/issues/71> before doing
         // anything about that.
         let _val = mem::uninitialized::<i32>();
         let _val = mem::uninitialized::<*const ()>();
+        let _val = mem::uninitialized::<[u8; 4]>();
     }
 }